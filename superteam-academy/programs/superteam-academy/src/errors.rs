@@ -97,4 +97,169 @@ pub enum AcademyError {
     
     #[msg("Course already exists")]
     CourseAlreadyExists,
+    
+    #[msg("Season has ended")]
+    SeasonEnded,
+    
+    #[msg("Season end must be in the future")]
+    InvalidSeasonEnd,
+    
+    #[msg("Season has no scheduled end or it has not passed")]
+    SeasonNotEnded,
+    
+    #[msg("Leaderboard not committed")]
+    LeaderboardNotCommitted,
+    
+    #[msg("Leaderboard already committed")]
+    LeaderboardAlreadyCommitted,
+    
+    #[msg("Invalid Merkle proof")]
+    InvalidMerkleProof,
+    
+    #[msg("Reward vault required for non-XP rewards")]
+    RewardVaultRequired,
+    
+    #[msg("Multiplier window must end after it starts")]
+    InvalidMultiplierWindow,
+    
+    #[msg("Multiplier out of range")]
+    InvalidMultiplier,
+    
+    #[msg("Multiplier window does not apply")]
+    MultiplierNotApplicable,
+    
+    #[msg("Lesson not completed")]
+    LessonNotCompleted,
+    
+    #[msg("Achievement not claimed")]
+    AchievementNotClaimed,
+    
+    #[msg("Metadata URI too long")]
+    MetadataUriTooLong,
+    
+    #[msg("No authority transfer pending")]
+    NoPendingAuthority,
+    
+    #[msg("Rotation deadline must be in the future")]
+    InvalidRotationDeadline,
+    
+    #[msg("Backend signer rotation already in progress")]
+    RotationInProgress,
+    
+    #[msg("Signer registry is full")]
+    SignerRegistryFull,
+    
+    #[msg("Signer not registered")]
+    SignerNotFound,
+    
+    #[msg("Signer already registered")]
+    SignerAlreadyRegistered,
+    
+    #[msg("Signer daily XP quota exceeded")]
+    SignerQuotaExceeded,
+    
+    #[msg("Unknown permission bits")]
+    InvalidPermissions,
+    
+    #[msg("Paused for maintenance")]
+    Paused,
+    
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+    
+    #[msg("Timelock must not be negative")]
+    InvalidTimelock,
+    
+    #[msg("Config timelock active: queue a pending change")]
+    TimelockRequired,
+    
+    #[msg("No config timelock set")]
+    TimelockNotEnabled,
+    
+    #[msg("Timelock has not elapsed")]
+    TimelockNotElapsed,
+    
+    #[msg("Invalid council members or threshold")]
+    InvalidCouncil,
+    
+    #[msg("Not a council member")]
+    NotCouncilMember,
+    
+    #[msg("Proposal expired")]
+    ProposalExpired,
+    
+    #[msg("Proposal already approved by this member")]
+    ProposalAlreadyApproved,
+    
+    #[msg("Proposal approval threshold not met")]
+    ProposalThresholdNotMet,
+    
+    #[msg("Account already on the current layout")]
+    AlreadyMigrated,
+    
+    #[msg("Course index page is full")]
+    CourseIndexFull,
+    
+    #[msg("Course index page out of order or wrong scope")]
+    InvalidCourseIndexPage,
+    
+    #[msg("Course index accounts required")]
+    CourseIndexRequired,
+    
+    #[msg("Course already indexed")]
+    CourseAlreadyIndexed,
+    
+    #[msg("Course not found in index")]
+    CourseNotIndexed,
+    
+    #[msg("Track id is reserved")]
+    ReservedTrackId,
+    
+    #[msg("Track name too long (max 32 chars)")]
+    TrackNameTooLong,
+    
+    #[msg("Track not active")]
+    TrackNotActive,
+    
+    #[msg("Track collection already created")]
+    TrackCollectionAlreadySet,
+    
+    #[msg("Track collection not created")]
+    TrackCollectionNotSet,
+    
+    #[msg("Collection does not match the course's track")]
+    InvalidTrackCollection,
+    
+    #[msg("Course already credited to this track")]
+    CourseAlreadyCredited,
+    
+    #[msg("Track progress full")]
+    TrackProgressFull,
+    
+    #[msg("Credential asset does not match the learner's track credential")]
+    InvalidCredentialAsset,
+    
+    #[msg("Credential revoked")]
+    CredentialRevoked,
+    
+    #[msg("Credential not revoked")]
+    CredentialNotRevoked,
+    
+    #[msg("Revocation reason too long (max 64 chars)")]
+    RevocationReasonTooLong,
+    
+    #[msg("Course index scope must be GLOBAL or an existing track")]
+    InvalidCourseIndexScope,
+    
+    #[msg("Track needs one level name (max 24 chars) per level")]
+    InvalidLevelNames,
+    
+    #[msg("Queued signer change needs the signer registry")]
+    SignerRegistryRequired,
+    
+    #[msg("Council membership changed since the proposal was created")]
+    StaleProposal,
+    
+    #[msg("Course cannot have more than 128 lessons")]
+    TooManyLessons,
 }
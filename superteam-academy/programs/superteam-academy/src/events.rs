@@ -27,6 +27,50 @@ pub struct CourseUpdated {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct CourseStatsInitialized {
+    pub course: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseIndexPageCreated {
+    pub course_index: Pubkey,
+    pub scope: u16,
+    pub page: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseIndexed {
+    pub course: Pubkey,
+    pub global_index_page: u16,
+    pub track_index_page: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackCreated {
+    pub track_id: u16,
+    pub name: String,
+    pub max_level: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackUpdated {
+    pub track_id: u16,
+    pub is_active: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TrackCollectionCreated {
+    pub track_id: u16,
+    pub collection: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct Enrolled {
     pub learner: Pubkey,
@@ -40,6 +84,8 @@ pub struct LessonCompleted {
     pub learner: Pubkey,
     pub course: Pubkey,
     pub lesson_index: u8,
+    pub base_xp: u32,
+    pub multiplier_bps: u16,
     pub xp_earned: u32,
     pub current_streak: u16,
     pub timestamp: i64,
@@ -59,6 +105,8 @@ pub struct CourseFinalized {
 pub struct CompletionBonusClaimed {
     pub learner: Pubkey,
     pub course: Pubkey,
+    pub base_bonus_xp: u32,
+    pub multiplier_bps: u16,
     pub bonus_xp: u32,
     pub timestamp: i64,
 }
@@ -74,6 +122,23 @@ pub struct CredentialIssued {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct CredentialRevoked {
+    pub learner: Pubkey,
+    pub track_id: u16,
+    pub credential_asset: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CredentialReinstated {
+    pub learner: Pubkey,
+    pub track_id: u16,
+    pub credential_asset: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AchievementClaimed {
     pub learner: Pubkey,
@@ -82,6 +147,28 @@ pub struct AchievementClaimed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct LevelUp {
+    pub learner: Pubkey,
+    pub old_level: u16,
+    pub new_level: u16,
+    pub lifetime_xp: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct XpRevoked {
+    pub learner: Pubkey,
+    pub season: u16,
+    pub amount: u64,
+    pub reason_code: u16,
+    pub course: Option<Pubkey>,
+    pub lesson_index: Option<u8>,
+    pub achievement_index: Option<u8>,
+    pub revoked_by: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct StreakBroken {
     pub learner: Pubkey,
@@ -128,6 +215,12 @@ pub struct ReferralRegistered {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct XpGroupCreated {
+    pub group: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct SeasonCreated {
     pub season: u16,
@@ -141,8 +234,194 @@ pub struct SeasonClosed {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct SeasonLeaderboardCommitted {
+    pub season: u16,
+    pub leaderboard_root: [u8; 32],
+    pub reward_mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonRewardClaimed {
+    pub season: u16,
+    pub learner: Pubkey,
+    pub rank: u16,
+    pub reward_mint: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultiplierWindowCreated {
+    pub window_id: u32,
+    pub multiplier_bps: u16,
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub track_id: Option<u16>,
+    pub course: Option<Pubkey>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct MultiplierWindowClosed {
+    pub window_id: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AcademyInitialized {
+    pub config: Pubkey,
+    pub academy_id: u16,
+    pub authority: Pubkey,
+    pub backend_signer: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct ConfigUpdated {
     pub field: String,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignerRegistryInitialized {
+    pub signer_registry: Pubkey,
+    pub backend_signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignerUpdated {
+    pub signer: Pubkey,
+    pub permissions: u8,
+    pub daily_xp_quota: u32,
+    pub expires_at: Option<i64>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignerRemoved {
+    pub signer: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SignerRotationStarted {
+    pub old_signer: Pubkey,
+    pub next_signer: Pubkey,
+    pub rotation_deadline: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PauseFlagsUpdated {
+    pub paused: u8,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CourseFreezeUpdated {
+    pub course: Pubkey,
+    pub frozen: bool,
+    pub updated_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeQueued {
+    pub change_id: u32,
+    pub proposed_by: Pubkey,
+    pub execute_after: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeExecuted {
+    pub change_id: u32,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ConfigChangeCancelled {
+    pub change_id: u32,
+    pub cancelled_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminCouncilCreated {
+    pub council: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCreated {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalApproved {
+    pub proposal_id: u64,
+    pub member: Pubkey,
+    pub approvals: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalExecuted {
+    pub proposal_id: u64,
+    pub executed_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminProposalCancelled {
+    pub proposal_id: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AdminCouncilUpdated {
+    pub council: Pubkey,
+    pub members: Vec<Pubkey>,
+    pub threshold: u8,
+    pub members_epoch: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub account_type: String,
+    pub schema_version: u8,
+    pub timestamp: i64,
+}
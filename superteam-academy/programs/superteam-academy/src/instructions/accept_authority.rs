@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AuthorityTransferred;
+
+/// Accept a proposed authority transfer (step 2 of 2)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Proposed authority; signing proves the key is usable
+    pub new_authority: Signer<'info>,
+}
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let new_authority = ctx.accounts.new_authority.key();
+    
+    let pending = config
+        .pending_authority
+        .ok_or(AcademyError::NoPendingAuthority)?;
+    require_keys_eq!(pending, new_authority, AcademyError::Unauthorized);
+    
+    let old_authority = config.authority;
+    config.authority = new_authority;
+    config.pending_authority = None;
+    
+    emit_cpi!(AuthorityTransferred {
+        old_authority,
+        new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Authority transferred: {} -> {}", old_authority, new_authority);
+    
+    Ok(())
+}
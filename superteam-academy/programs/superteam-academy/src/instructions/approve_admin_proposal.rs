@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AdminProposalApproved;
+
+/// Approve an admin proposal
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ApproveAdminProposal<'info> {
+    /// Council member
+    pub member: Signer<'info>,
+    
+    /// AdminCouncil PDA
+    #[account(
+        seeds = [AdminCouncil::SEED, admin_council.config.as_ref()],
+        bump = admin_council.bump,
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+    
+    /// AdminProposal PDA
+    #[account(
+        mut,
+        seeds = [AdminProposal::SEED, admin_council.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+    let member = ctx.accounts.member.key();
+    let proposal = &mut ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    let index = ctx
+        .accounts
+        .admin_council
+        .member_index(&member)
+        .ok_or(AcademyError::NotCouncilMember)?;
+    require!(now < proposal.expires_at, AcademyError::ProposalExpired);
+    require!(
+        proposal.members_epoch == ctx.accounts.admin_council.members_epoch,
+        AcademyError::StaleProposal
+    );
+    require!(proposal.approve(index), AcademyError::ProposalAlreadyApproved);
+    
+    emit_cpi!(AdminProposalApproved {
+        proposal_id: proposal.proposal_id,
+        member,
+        approvals: proposal.approval_count(),
+        timestamp: now,
+    });
+    
+    msg!(
+        "Admin proposal {} approved by {} ({} approvals)",
+        proposal.proposal_id,
+        member,
+        proposal.approval_count()
+    );
+    
+    Ok(())
+}
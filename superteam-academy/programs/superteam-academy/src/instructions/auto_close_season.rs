@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::SeasonClosed;
+
+/// Close current season once its scheduled end has passed (permissionless crank)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AutoCloseSeason<'info> {
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Season archive PDA for the current season
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
+    /// Anyone may crank
+    pub cranker: Signer<'info>,
+}
+
+pub fn auto_close_season(ctx: Context<AutoCloseSeason>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let season_record = &mut ctx.accounts.season_record;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(!config.season_closed, AcademyError::SeasonClosed);
+    
+    // Only after the scheduled end
+    let ends_at = config.season_ends_at.ok_or(AcademyError::SeasonNotEnded)?;
+    require!(now >= ends_at, AcademyError::SeasonNotEnded);
+    
+    config.season_closed = true;
+    
+    // Finalize archive record at the scheduled end
+    season_record.ended_at = Some(ends_at);
+    season_record.is_closed = true;
+    
+    emit_cpi!(SeasonClosed {
+        season: config.current_season,
+        timestamp: ends_at,
+    });
+    
+    msg!("Season {} auto-closed at {}", config.current_season, ends_at);
+    
+    Ok(())
+}
@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::StreakFreezeAwarded;
 
 /// Award a streak freeze to learner
+#[event_cpi]
 #[derive(Accounts)]
 pub struct AwardStreakFreeze<'info> {
     /// Backend signer
@@ -11,25 +13,37 @@ pub struct AwardStreakFreeze<'info> {
     
     /// Config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = backend_signer @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
+    /// Backend signer registry
+    #[account(
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
     /// Learner wallet
     pub learner: SystemAccount<'info>,
     
     /// LearnerProfile PDA
     #[account(
         mut,
-        seeds = [LearnerProfile::SEED, learner.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
         bump = learner_profile.bump,
     )]
     pub learner_profile: Account<'info, LearnerProfile>,
 }
 
 pub fn award_streak_freeze(ctx: Context<AwardStreakFreeze>) -> Result<()> {
+    ctx.accounts.signer_registry.check(
+        ctx.accounts.backend_signer.key,
+        SignerRegistry::PERM_STREAK_FREEZE,
+        Clock::get()?.unix_timestamp,
+    )?;
+    
     let learner_profile = &mut ctx.accounts.learner_profile;
     
     // Increment streak freezes
@@ -38,6 +52,12 @@ pub fn award_streak_freeze(ctx: Context<AwardStreakFreeze>) -> Result<()> {
         .checked_add(1)
         .ok_or(AcademyError::Overflow)?;
     
+    emit_cpi!(StreakFreezeAwarded {
+        learner: ctx.accounts.learner.key(),
+        freezes_remaining: learner_profile.streak_freezes,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     msg!(
         "Streak freeze awarded to: {}, total: {}",
         ctx.accounts.learner.key(),
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::AdminProposalCancelled;
+
+/// Cancel an admin proposal (proposer only)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAdminProposal<'info> {
+    /// Proposer (rent refund)
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    /// AdminCouncil PDA
+    #[account(
+        seeds = [AdminCouncil::SEED, admin_council.config.as_ref()],
+        bump = admin_council.bump,
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+    
+    /// AdminProposal PDA
+    #[account(
+        mut,
+        seeds = [AdminProposal::SEED, admin_council.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+}
+
+pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+    let proposal_id = ctx.accounts.proposal.proposal_id;
+    
+    emit_cpi!(AdminProposalCancelled {
+        proposal_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Admin proposal {} cancelled", proposal_id);
+    
+    Ok(())
+}
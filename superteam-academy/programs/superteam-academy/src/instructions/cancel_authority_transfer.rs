@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AuthorityTransferCancelled;
+
+/// Cancel a pending authority transfer
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelAuthorityTransfer<'info> {
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Current platform authority
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    let pending_authority = config
+        .pending_authority
+        .take()
+        .ok_or(AcademyError::NoPendingAuthority)?;
+    
+    emit_cpi!(AuthorityTransferCancelled {
+        authority: config.authority,
+        pending_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Authority transfer to {} cancelled", pending_authority);
+    
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::ConfigChangeCancelled;
+
+/// Drop a queued config change
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelConfigChange<'info> {
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// Queued change
+    #[account(
+        mut,
+        seeds = [PendingConfigChange::SEED, config.key().as_ref(), &pending_change.change_id.to_le_bytes()],
+        bump = pending_change.bump,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+    
+    /// Rent refund destination
+    /// CHECK: Verified against pending_change.rent_payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+}
+
+pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+    let change_id = ctx.accounts.pending_change.change_id;
+    
+    emit_cpi!(ConfigChangeCancelled {
+        change_id,
+        cancelled_by: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Config change {} cancelled", change_id);
+    
+    Ok(())
+}
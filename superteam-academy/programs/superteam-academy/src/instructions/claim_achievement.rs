@@ -3,9 +3,11 @@ use anchor_spl::token_interface::{Token2022, TokenAccount, Mint, MintTo};
 
 use crate::state::*;
 use crate::error::AcademyError;
-use crate::utils::check_and_update_daily_xp;
+use crate::events::{AchievementClaimed, LevelUp};
+use crate::utils::{check_and_update_daily_xp, credit_learner_xp};
 
 /// Claim an achievement
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(achievement_index: u8, xp_reward: u32)]
 pub struct ClaimAchievement<'info> {
@@ -15,12 +17,19 @@ pub struct ClaimAchievement<'info> {
     /// Config PDA
     #[account(
         mut,
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = backend_signer @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
+    /// Backend signer registry
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
     /// Learner wallet
     /// CHECK: Verified by PDA
     pub learner: AccountInfo<'info>,
@@ -28,12 +37,20 @@ pub struct ClaimAchievement<'info> {
     /// LearnerProfile PDA
     #[account(
         mut,
-        seeds = [LearnerProfile::SEED, learner.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
         bump = learner_profile.bump,
         constraint = learner_profile.authority == learner.key() @ AcademyError::Unauthorized,
     )]
     pub learner_profile: Account<'info, LearnerProfile>,
     
+    /// Season archive PDA for the current season
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
     /// XP Mint (Token-2022)
     #[account(
         mut,
@@ -52,7 +69,7 @@ pub struct ClaimAchievement<'info> {
     /// Config PDA as mint authority
     /// CHECK: Derived from config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
     )]
     pub config_pda: AccountInfo<'info>,
@@ -73,6 +90,10 @@ pub fn claim_achievement(
     let config = &mut ctx.accounts.config;
     let learner_profile = &mut ctx.accounts.learner_profile;
     
+    // Check season not closed or past its scheduled end
+    config.require_season_active(Clock::get()?.unix_timestamp)?;
+    config.require_not_paused(Config::PAUSE_ACHIEVEMENTS)?;
+    
     // Check not already claimed
     require!(
         !learner_profile.is_achievement_claimed(achievement_index),
@@ -82,6 +103,14 @@ pub fn claim_achievement(
     // Cap XP reward
     let capped_reward = xp_reward.min(config.max_achievement_xp);
     
+    // Signer role and quota
+    ctx.accounts.signer_registry.authorize(
+        ctx.accounts.backend_signer.key,
+        SignerRegistry::PERM_ACHIEVEMENTS,
+        capped_reward,
+        Clock::get()?.unix_timestamp,
+    )?;
+    
     // Check daily rate limit
     check_and_update_daily_xp(learner_profile, config, capped_reward)?;
     
@@ -90,7 +119,8 @@ pub fn claim_achievement(
     
     // Mint XP tokens
     let xp_amount = capped_reward as u64;
-    let config_seeds = &[Config::SEED, &[config.bump]];
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[config.bump]];
     let signer_seeds = &[&config_seeds[..]];
     
     let cpi_accounts = MintTo {
@@ -105,6 +135,32 @@ pub fn claim_achievement(
     );
     anchor_spl::token_interface::mint_to(cpi_ctx, xp_amount)?;
     
+    // Season totals
+    ctx.accounts.season_record.record_xp(xp_amount)?;
+    
+    // Lifetime/season XP and level
+    if let Some((old_level, new_level)) = credit_learner_xp(
+        learner_profile,
+        config,
+        xp_amount,
+        config.current_season,
+    )? {
+        emit_cpi!(LevelUp {
+            learner: ctx.accounts.learner.key(),
+            old_level,
+            new_level,
+            lifetime_xp: learner_profile.lifetime_xp,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+    }
+    
+    emit_cpi!(AchievementClaimed {
+        learner: ctx.accounts.learner.key(),
+        achievement_index,
+        xp_reward: capped_reward,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     msg!(
         "Achievement claimed: index={}, xp={}",
         achievement_index,
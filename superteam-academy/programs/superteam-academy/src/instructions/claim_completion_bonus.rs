@@ -3,9 +3,11 @@ use anchor_spl::token_interface::{Token2022, TokenAccount, Mint, MintTo};
 
 use crate::state::*;
 use crate::error::AcademyError;
-use crate::utils::check_and_update_daily_xp;
+use crate::events::{CompletionBonusClaimed, LevelUp};
+use crate::utils::{check_and_update_daily_xp, credit_learner_xp, apply_multiplier};
 
 /// Claim completion bonus XP
+#[event_cpi]
 #[derive(Accounts)]
 pub struct ClaimCompletionBonus<'info> {
     /// Learner wallet
@@ -13,14 +15,14 @@ pub struct ClaimCompletionBonus<'info> {
     
     /// Config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
     )]
     pub config: Account<'info, Config>,
     
     /// Course PDA
     #[account(
-        seeds = Course::seeds(&course.course_id),
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
@@ -28,7 +30,7 @@ pub struct ClaimCompletionBonus<'info> {
     /// LearnerProfile PDA
     #[account(
         mut,
-        seeds = [LearnerProfile::SEED, learner.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
         bump = learner_profile.bump,
     )]
     pub learner_profile: Account<'info, LearnerProfile>,
@@ -38,6 +40,7 @@ pub struct ClaimCompletionBonus<'info> {
         mut,
         seeds = [
             b"enrollment",
+            &config.scope_seed(&config.key()),
             course.course_id.as_bytes(),
             learner.key().as_ref(),
         ],
@@ -45,6 +48,14 @@ pub struct ClaimCompletionBonus<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
     
+    /// Season archive PDA for the current season
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
     /// XP Mint (Token-2022)
     #[account(
         mut,
@@ -63,11 +74,19 @@ pub struct ClaimCompletionBonus<'info> {
     /// Config PDA as mint authority
     /// CHECK: Derived from config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
     )]
     pub config_pda: AccountInfo<'info>,
     
+    /// Optional active XP multiplier window
+    #[account(
+        seeds = [MultiplierWindow::SEED, config.key().as_ref(), &multiplier_window.window_id.to_le_bytes()],
+        bump = multiplier_window.bump,
+        has_one = config @ AcademyError::Unauthorized,
+    )]
+    pub multiplier_window: Option<Account<'info, MultiplierWindow>>,
+    
     pub token_program: Program<'info, Token2022>,
 }
 
@@ -76,6 +95,12 @@ pub fn claim_completion_bonus(ctx: Context<ClaimCompletionBonus>) -> Result<()>
     let enrollment = &mut ctx.accounts.enrollment;
     let learner_profile = &mut ctx.accounts.learner_profile;
     let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    
+    // Check season not closed or past its scheduled end
+    config.require_season_active(now)?;
+    config.require_not_paused(Config::PAUSE_BONUSES)?;
+    course.require_not_frozen()?;
     
     // Must be finalized
     require!(
@@ -89,15 +114,25 @@ pub fn claim_completion_bonus(ctx: Context<ClaimCompletionBonus>) -> Result<()>
         AcademyError::BonusAlreadyClaimed
     );
     
-    // Check daily cap
-    check_and_update_daily_xp(learner_profile, config, course.completion_bonus_xp)?;
+    // Apply multiplier window (if any)
+    let (bonus_xp, multiplier_bps) = apply_multiplier(
+        ctx.accounts.multiplier_window.as_deref(),
+        &course.key(),
+        course,
+        course.completion_bonus_xp,
+        now,
+    )?;
+    
+    // Check daily cap (boosted amount)
+    check_and_update_daily_xp(learner_profile, config, bonus_xp)?;
     
     // Mark claimed
     enrollment.bonus_claimed = true;
     
     // Mint completion bonus XP
-    let bonus_amount = course.completion_bonus_xp as u64;
-    let config_seeds = &[Config::SEED, &[config.bump]];
+    let bonus_amount = bonus_xp as u64;
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[config.bump]];
     let signer_seeds = &[&config_seeds[..]];
     
     let cpi_accounts = MintTo {
@@ -112,6 +147,34 @@ pub fn claim_completion_bonus(ctx: Context<ClaimCompletionBonus>) -> Result<()>
     );
     anchor_spl::token_interface::mint_to(cpi_ctx, bonus_amount)?;
     
+    // Season totals
+    ctx.accounts.season_record.record_xp(bonus_amount)?;
+    
+    // Lifetime/season XP and level
+    if let Some((old_level, new_level)) = credit_learner_xp(
+        learner_profile,
+        config,
+        bonus_amount,
+        config.current_season,
+    )? {
+        emit_cpi!(LevelUp {
+            learner: ctx.accounts.learner.key(),
+            old_level,
+            new_level,
+            lifetime_xp: learner_profile.lifetime_xp,
+            timestamp: now,
+        });
+    }
+    
+    emit_cpi!(CompletionBonusClaimed {
+        learner: ctx.accounts.learner.key(),
+        course: course.key(),
+        base_bonus_xp: course.completion_bonus_xp,
+        multiplier_bps,
+        bonus_xp,
+        timestamp: now,
+    });
+    
     msg!(
         "Completion bonus claimed: {} XP for {}",
         bonus_amount,
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    Mint, MintTo, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::{LevelUp, SeasonRewardClaimed};
+use crate::utils::{season_reward_leaf, verify_merkle_proof, credit_learner_xp};
+
+/// Claim a season leaderboard prize with a Merkle proof
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(season: u16)]
+pub struct ClaimSeasonReward<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Learner wallet
+    pub learner: Signer<'info>,
+    
+    /// LearnerProfile PDA (required for season XP prizes)
+    #[account(
+        mut,
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
+        bump = learner_profile.bump,
+    )]
+    pub learner_profile: Option<Account<'info, LearnerProfile>>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Season archive PDA
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
+    /// Claim receipt (init fails on a second claim)
+    #[account(
+        init,
+        payer = payer,
+        space = SeasonRewardClaim::SIZE,
+        seeds = [
+            SeasonRewardClaim::SEED,
+            config.key().as_ref(),
+            &season.to_le_bytes(),
+            learner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub reward_claim: Account<'info, SeasonRewardClaim>,
+    
+    /// Prize mint
+    #[account(
+        mut,
+        address = season_record.reward_mint @ AcademyError::LeaderboardNotCommitted,
+        mint::token_program = token_program,
+    )]
+    pub reward_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Prize vault (required unless paying in season XP)
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = config_pda,
+        token::token_program = token_program,
+    )]
+    pub reward_vault: Option<InterfaceAccount<'info, TokenAccount>>,
+    
+    /// Learner's token account for the prize mint
+    #[account(
+        mut,
+        token::mint = reward_mint,
+        token::authority = learner,
+        token::token_program = token_program,
+    )]
+    pub learner_token: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Config PDA as mint/vault authority
+    /// CHECK: Derived from config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config_pda: AccountInfo<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn claim_season_reward(
+    ctx: Context<ClaimSeasonReward>,
+    season: u16,
+    rank: u16,
+    season_xp: u64,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let season_record = &mut ctx.accounts.season_record;
+    let learner_key = ctx.accounts.learner.key();
+    let now = Clock::get()?.unix_timestamp;
+    
+    // Standings must be committed
+    let root = season_record
+        .leaderboard_root
+        .ok_or(AcademyError::LeaderboardNotCommitted)?;
+    
+    // Verify leaf against committed root
+    let leaf = season_reward_leaf(&learner_key, rank, season_xp, amount);
+    require!(
+        verify_merkle_proof(&proof, &root, leaf),
+        AcademyError::InvalidMerkleProof
+    );
+    
+    let academy_seed = Config::academy_seed(ctx.accounts.config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[ctx.accounts.config.bump]];
+    let signer_seeds = &[&config_seeds[..]];
+    
+    if amount > 0 {
+        if ctx.accounts.reward_mint.key() == season_record.mint {
+            // Season XP is non-transferable: mint directly
+            let cpi_accounts = MintTo {
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.learner_token.to_account_info(),
+                authority: ctx.accounts.config_pda.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            anchor_spl::token_interface::mint_to(cpi_ctx, amount)?;
+            // Counted in rewards_paid only: a closed season's minted
+            // total stays final
+            
+            // Lifetime XP and level (minted in a past season)
+            let learner_profile = ctx
+                .accounts
+                .learner_profile
+                .as_mut()
+                .ok_or(AcademyError::LearnerNotInitialized)?;
+            if let Some((old_level, new_level)) = credit_learner_xp(
+                learner_profile,
+                &ctx.accounts.config,
+                amount,
+                season,
+            )? {
+                emit_cpi!(LevelUp {
+                    learner: learner_key,
+                    old_level,
+                    new_level,
+                    lifetime_xp: learner_profile.lifetime_xp,
+                    timestamp: now,
+                });
+            }
+        } else {
+            // SPL prize: pay out of the vault
+            let vault = ctx
+                .accounts
+                .reward_vault
+                .as_ref()
+                .ok_or(AcademyError::RewardVaultRequired)?;
+            let cpi_accounts = TransferChecked {
+                from: vault.to_account_info(),
+                mint: ctx.accounts.reward_mint.to_account_info(),
+                to: ctx.accounts.learner_token.to_account_info(),
+                authority: ctx.accounts.config_pda.clone(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            anchor_spl::token_interface::transfer_checked(
+                cpi_ctx,
+                amount,
+                ctx.accounts.reward_mint.decimals,
+            )?;
+        }
+    }
+    
+    season_record.record_reward(amount)?;
+    
+    // Write receipt
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    reward_claim.season = season;
+    reward_claim.learner = learner_key;
+    reward_claim.rank = rank;
+    reward_claim.season_xp = season_xp;
+    reward_claim.amount = amount;
+    reward_claim.claimed_at = now;
+    reward_claim.bump = ctx.bumps.reward_claim;
+    
+    emit_cpi!(SeasonRewardClaimed {
+        season,
+        learner: learner_key,
+        rank,
+        reward_mint: ctx.accounts.reward_mint.key(),
+        amount,
+        timestamp: now,
+    });
+    
+    msg!(
+        "Season {} reward claimed: rank {}, {} to {}",
+        season,
+        rank,
+        amount,
+        learner_key
+    );
+    
+    Ok(())
+}
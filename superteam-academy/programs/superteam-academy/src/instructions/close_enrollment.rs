@@ -2,17 +2,27 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::EnrollmentClosed;
 
 /// Close enrollment (completed or abandoned)
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseEnrollment<'info> {
     /// Learner wallet (receives rent back)
     #[account(mut)]
     pub learner: Signer<'info>,
     
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
     /// Course PDA
     #[account(
-        seeds = Course::seeds(&course.course_id),
+        mut,
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
@@ -22,6 +32,7 @@ pub struct CloseEnrollment<'info> {
         mut,
         seeds = [
             b"enrollment",
+            &config.scope_seed(&config.key()),
             course.course_id.as_bytes(),
             learner.key().as_ref(),
         ],
@@ -43,8 +54,17 @@ pub fn close_enrollment(ctx: Context<CloseEnrollment>) -> Result<()> {
             now - enrollment.enrolled_at >= 86400,
             AcademyError::UnenrollCooldown
         );
+        ctx.accounts.course.record_abandonment()?;
     }
     
+    emit_cpi!(EnrollmentClosed {
+        learner: ctx.accounts.learner.key(),
+        course: ctx.accounts.course.key(),
+        completed: is_completed,
+        rent_reclaimed: enrollment.to_account_info().lamports(),
+        timestamp: now,
+    });
+    
     msg!(
         "Enrollment closed: {} completed={}",
         ctx.accounts.course.course_id,
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::MultiplierWindowClosed;
+
+/// Cancel or clean up an XP multiplier window
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseMultiplierWindow<'info> {
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority (receives rent back)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    /// MultiplierWindow PDA to close
+    #[account(
+        mut,
+        seeds = [MultiplierWindow::SEED, config.key().as_ref(), &multiplier_window.window_id.to_le_bytes()],
+        bump = multiplier_window.bump,
+        close = authority,
+    )]
+    pub multiplier_window: Account<'info, MultiplierWindow>,
+}
+
+pub fn close_multiplier_window(ctx: Context<CloseMultiplierWindow>) -> Result<()> {
+    emit_cpi!(MultiplierWindowClosed {
+        window_id: ctx.accounts.multiplier_window.window_id,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!(
+        "Multiplier window {} closed",
+        ctx.accounts.multiplier_window.window_id
+    );
+    
+    Ok(())
+}
@@ -1,29 +1,73 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::SeasonClosed;
 
 /// Close current season (no more XP minting)
+#[event_cpi]
 #[derive(Accounts)]
 pub struct CloseSeason<'info> {
     /// Config PDA
     #[account(
         mut,
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = authority @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
-    /// Platform authority
+    /// Season archive PDA for the current season
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
+    /// Platform authority or admin council
+    #[account(
+        constraint = config.is_admin(authority.key) @ AcademyError::Unauthorized,
+    )]
     pub authority: Signer<'info>,
+    
+    /// Optional prize mint (defaults to the season XP mint)
+    pub reward_mint: Option<InterfaceAccount<'info, Mint>>,
 }
 
-pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
+pub fn close_season(
+    ctx: Context<CloseSeason>,
+    leaderboard_root: Option<[u8; 32]>,
+) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    let season_record = &mut ctx.accounts.season_record;
+    
+    require!(!config.season_closed, AcademyError::SeasonClosed);
     
     config.season_closed = true;
     
+    // Finalize archive record
+    let now = Clock::get()?.unix_timestamp;
+    season_record.ended_at = Some(now);
+    season_record.is_closed = true;
+    
+    // Commit final standings if provided
+    if let Some(root) = leaderboard_root {
+        let reward_mint = ctx
+            .accounts
+            .reward_mint
+            .as_ref()
+            .map(|mint| mint.key())
+            .unwrap_or(season_record.mint);
+        season_record.commit_leaderboard(root, reward_mint)?;
+        msg!("Leaderboard committed, rewards in {}", reward_mint);
+    }
+    
+    emit_cpi!(SeasonClosed {
+        season: config.current_season,
+        timestamp: now,
+    });
+    
     msg!("Season {} closed", config.current_season);
     
     Ok(())
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::SeasonLeaderboardCommitted;
+
+/// Commit final standings for an already closed season
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(season: u16)]
+pub struct CommitSeasonLeaderboard<'info> {
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Season archive PDA
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// Optional prize mint (defaults to the season XP mint)
+    pub reward_mint: Option<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn commit_season_leaderboard(
+    ctx: Context<CommitSeasonLeaderboard>,
+    season: u16,
+    leaderboard_root: [u8; 32],
+) -> Result<()> {
+    let season_record = &mut ctx.accounts.season_record;
+    
+    let reward_mint = ctx
+        .accounts
+        .reward_mint
+        .as_ref()
+        .map(|mint| mint.key())
+        .unwrap_or(season_record.mint);
+    season_record.commit_leaderboard(leaderboard_root, reward_mint)?;
+    
+    emit_cpi!(SeasonLeaderboardCommitted {
+        season,
+        leaderboard_root,
+        reward_mint,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Season {} leaderboard committed", season);
+    msg!("Rewards in: {}", reward_mint);
+    
+    Ok(())
+}
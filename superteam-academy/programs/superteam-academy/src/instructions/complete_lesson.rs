@@ -4,9 +4,16 @@ use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
 
 use crate::state::*;
 use crate::error::AcademyError;
-use crate::utils::{update_streak, check_and_update_daily_xp};
+use crate::events::{
+    LessonCompleted, LevelUp, StreakBroken, StreakFreezesUsed, StreakMilestone,
+};
+use crate::utils::{
+    update_streak, check_milestone, check_and_update_daily_xp, credit_learner_xp, apply_multiplier,
+    StreakUpdate,
+};
 
 /// Complete a lesson
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(lesson_index: u8)]
 pub struct CompleteLesson<'info> {
@@ -16,16 +23,23 @@ pub struct CompleteLesson<'info> {
     /// Config PDA
     #[account(
         mut,
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = backend_signer @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
+    /// Backend signer registry
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
     /// Course PDA
     #[account(
         mut,
-        seeds = Course::seeds(&course.course_id),
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
@@ -37,7 +51,7 @@ pub struct CompleteLesson<'info> {
     /// LearnerProfile PDA
     #[account(
         mut,
-        seeds = [LearnerProfile::SEED, learner.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
         bump = learner_profile.bump,
         constraint = learner_profile.authority == learner.key() @ AcademyError::Unauthorized,
     )]
@@ -48,6 +62,7 @@ pub struct CompleteLesson<'info> {
         mut,
         seeds = [
             b"enrollment",
+            &config.scope_seed(&config.key()),
             course.course_id.as_bytes(),
             learner.key().as_ref(),
         ],
@@ -55,6 +70,22 @@ pub struct CompleteLesson<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
     
+    /// CourseStats PDA (lesson funnel)
+    #[account(
+        mut,
+        seeds = [CourseStats::SEED, course.key().as_ref()],
+        bump = course_stats.bump,
+    )]
+    pub course_stats: Box<Account<'info, CourseStats>>,
+    
+    /// Season archive PDA for the current season
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
     /// XP Mint (Token-2022)
     #[account(
         mut,
@@ -73,11 +104,19 @@ pub struct CompleteLesson<'info> {
     /// Config PDA as mint authority
     /// CHECK: Derived from config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
     )]
     pub config_pda: AccountInfo<'info>,
     
+    /// Optional active XP multiplier window
+    #[account(
+        seeds = [MultiplierWindow::SEED, config.key().as_ref(), &multiplier_window.window_id.to_le_bytes()],
+        bump = multiplier_window.bump,
+        has_one = config @ AcademyError::Unauthorized,
+    )]
+    pub multiplier_window: Option<Account<'info, MultiplierWindow>>,
+    
     pub token_program: Program<'info, Token2022>,
 }
 
@@ -89,9 +128,12 @@ pub fn complete_lesson(
     let enrollment = &mut ctx.accounts.enrollment;
     let learner_profile = &mut ctx.accounts.learner_profile;
     let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
     
-    // Check season not closed
-    require!(!config.season_closed, AcademyError::SeasonClosed);
+    // Check season not closed or past its scheduled end
+    config.require_season_active(now)?;
+    config.require_not_paused(Config::PAUSE_LESSONS)?;
+    course.require_not_frozen()?;
     
     // Check lesson bounds
     require!(
@@ -103,16 +145,36 @@ pub fn complete_lesson(
     let is_new = enrollment.complete_lesson(lesson_index);
     require!(is_new, AcademyError::LessonAlreadyCompleted);
     
-    // Check daily XP cap
-    check_and_update_daily_xp(learner_profile, config, course.xp_per_lesson)?;
+    // Lesson funnel
+    ctx.accounts.course_stats.record_lesson(lesson_index, now)?;
+    
+    // Apply multiplier window (if any)
+    let (lesson_xp, multiplier_bps) = apply_multiplier(
+        ctx.accounts.multiplier_window.as_deref(),
+        &course.key(),
+        course,
+        course.xp_per_lesson,
+        now,
+    )?;
+    
+    // Signer role and quota (boosted amount)
+    ctx.accounts.signer_registry.authorize(
+        ctx.accounts.backend_signer.key,
+        SignerRegistry::PERM_COMPLETE_LESSON,
+        lesson_xp,
+        now,
+    )?;
+    
+    // Check daily XP cap (boosted amount)
+    check_and_update_daily_xp(learner_profile, config, lesson_xp)?;
     
     // Update streak
     let streak_update = update_streak(learner_profile)?;
     
     // Mint XP tokens
-    let xp_amount = course.xp_per_lesson as u64;
-    let config_key = ctx.accounts.config.key();
-    let config_seeds = &[Config::SEED, &[config.bump]];
+    let xp_amount = lesson_xp as u64;
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[config.bump]];
     let signer_seeds = &[&config_seeds[..]];
     
     let cpi_accounts = MintTo {
@@ -127,6 +189,38 @@ pub fn complete_lesson(
     );
     anchor_spl::token_interface::mint_to(cpi_ctx, xp_amount)?;
     
+    // Season totals
+    let season_record = &mut ctx.accounts.season_record;
+    season_record.record_xp(xp_amount)?;
+    season_record.record_lesson()?;
+    
+    // Lifetime/season XP and level
+    if let Some((old_level, new_level)) = credit_learner_xp(
+        learner_profile,
+        config,
+        xp_amount,
+        config.current_season,
+    )? {
+        emit_cpi!(LevelUp {
+            learner: ctx.accounts.learner.key(),
+            old_level,
+            new_level,
+            lifetime_xp: learner_profile.lifetime_xp,
+            timestamp: now,
+        });
+    }
+    
+    emit_cpi!(LessonCompleted {
+        learner: ctx.accounts.learner.key(),
+        course: course.key(),
+        lesson_index,
+        base_xp: course.xp_per_lesson,
+        multiplier_bps,
+        xp_earned: lesson_xp,
+        current_streak: learner_profile.current_streak,
+        timestamp: now,
+    });
+    
     msg!(
         "Lesson completed: {} - lesson {}, earned {} XP",
         course.course_id,
@@ -135,16 +229,40 @@ pub fn complete_lesson(
     );
     
     if let Some(update) = streak_update {
-        match update {
-            crate::utils::StreakUpdate::Continued { new_streak } => {
+        let learner = ctx.accounts.learner.key();
+        let new_streak = match update {
+            StreakUpdate::Continued { new_streak } => {
                 msg!("Streak continued: {}", new_streak);
+                new_streak
             }
-            crate::utils::StreakUpdate::SavedByFreezes { freezes_used, new_streak } => {
+            StreakUpdate::SavedByFreezes { freezes_used, new_streak } => {
+                emit_cpi!(StreakFreezesUsed {
+                    learner,
+                    freezes_used,
+                    freezes_remaining: learner_profile.streak_freezes,
+                    timestamp: now,
+                });
                 msg!("Streak saved by {} freezes, now: {}", freezes_used, new_streak);
+                new_streak
             }
-            crate::utils::StreakUpdate::Broken { old_streak, days_missed } => {
+            StreakUpdate::Broken { old_streak, days_missed } => {
+                emit_cpi!(StreakBroken {
+                    learner,
+                    final_streak: old_streak,
+                    days_missed,
+                    timestamp: now,
+                });
                 msg!("Streak broken after {} days, missed {} days", old_streak, days_missed);
+                learner_profile.current_streak
             }
+        };
+        
+        if let Some(milestone) = check_milestone(new_streak) {
+            emit_cpi!(StreakMilestone {
+                learner,
+                milestone,
+                timestamp: now,
+            });
         }
     }
     
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AdminProposalCreated;
+
+/// Propose an admin action (proposer approves implicitly)
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(action: AdminAction)]
+pub struct CreateAdminProposal<'info> {
+    /// Council member
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    /// AdminCouncil PDA
+    #[account(
+        mut,
+        seeds = [AdminCouncil::SEED, admin_council.config.as_ref()],
+        bump = admin_council.bump,
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+    
+    /// AdminProposal PDA
+    #[account(
+        init,
+        payer = proposer,
+        space = AdminProposal::space(&action),
+        seeds = [
+            AdminProposal::SEED,
+            admin_council.key().as_ref(),
+            &admin_council.next_proposal_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_admin_proposal(
+    ctx: Context<CreateAdminProposal>,
+    action: AdminAction,
+    expires_at: i64,
+) -> Result<()> {
+    let council = &mut ctx.accounts.admin_council;
+    let proposer = ctx.accounts.proposer.key();
+    let now = Clock::get()?.unix_timestamp;
+    
+    let index = council
+        .member_index(&proposer)
+        .ok_or(AcademyError::NotCouncilMember)?;
+    require!(expires_at > now, AcademyError::ProposalExpired);
+    
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.proposal_id = council.next_proposal_id;
+    proposal.proposer = proposer;
+    proposal.action = action;
+    proposal.approvals = 0;
+    proposal.approve(index);
+    proposal.created_at = now;
+    proposal.expires_at = expires_at;
+    proposal.members_epoch = council.members_epoch;
+    proposal.bump = ctx.bumps.proposal;
+    
+    council.next_proposal_id = council
+        .next_proposal_id
+        .checked_add(1)
+        .ok_or(AcademyError::Overflow)?;
+    
+    emit_cpi!(AdminProposalCreated {
+        proposal_id: proposal.proposal_id,
+        proposer,
+        expires_at,
+        timestamp: now,
+    });
+    
+    msg!("Admin proposal {} created", proposal.proposal_id);
+    
+    Ok(())
+}
@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::CourseCreated;
+use crate::utils::add_to_course_index;
 
 /// Register a new course
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(course_id: String, params: CreateCourseParams)]
 pub struct CreateCourse<'info> {
@@ -12,25 +15,70 @@ pub struct CreateCourse<'info> {
     
     /// Config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = authority @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
-    /// Platform authority
+    /// Platform authority or admin council
+    #[account(
+        constraint = config.is_admin(authority.key) @ AcademyError::Unauthorized,
+    )]
     pub authority: Signer<'info>,
     
+    /// Track the course belongs to
+    #[account(
+        seeds = [Track::SEED, config.key().as_ref(), &params.track_id.to_le_bytes()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+    
     /// Course PDA
     #[account(
         init,
         payer = payer,
         space = Course::SIZE,
-        seeds = Course::seeds(&course_id),
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course_id.as_bytes()],
         bump,
     )]
     pub course: Account<'info, Course>,
     
+    /// CourseStats PDA (lesson funnel)
+    #[account(
+        init,
+        payer = payer,
+        space = CourseStats::SIZE,
+        seeds = [CourseStats::SEED, course.key().as_ref()],
+        bump,
+    )]
+    pub course_stats: Box<Account<'info, CourseStats>>,
+    
+    /// Open page of the global CourseIndex
+    #[account(
+        mut,
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &CourseIndex::GLOBAL.to_le_bytes(),
+            &global_index.page.to_le_bytes(),
+        ],
+        bump = global_index.bump,
+    )]
+    pub global_index: Box<Account<'info, CourseIndex>>,
+    
+    /// Open page of the track CourseIndex
+    #[account(
+        mut,
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &params.track_id.to_le_bytes(),
+            &track_index.page.to_le_bytes(),
+        ],
+        bump = track_index.bump,
+    )]
+    pub track_index: Box<Account<'info, CourseIndex>>,
+    
     /// Optional prerequisite course
     pub prerequisite: Option<Account<'info, Course>>,
     
@@ -69,11 +117,19 @@ pub fn create_course(
         AcademyError::InvalidDifficulty
     );
     
+    // Enrollment bitmap and CourseStats hold 128 lessons
+    require!(
+        params.lesson_count as usize <= CourseStats::MAX_LESSONS,
+        AcademyError::TooManyLessons
+    );
+    
     require!(
-        params.track_level >= 1 && params.track_level <= 3,
-        AcademyError::InvalidTrackLevel
+        params.track_id != CourseIndex::GLOBAL,
+        AcademyError::ReservedTrackId
     );
     
+    ctx.accounts.track.require_accepts(params.track_level)?;
+    
     let course = &mut ctx.accounts.course;
     let now = Clock::get()?.unix_timestamp;
     
@@ -94,10 +150,40 @@ pub fn create_course(
     course.total_completions = 0;
     course.total_enrollments = 0;
     course.is_active = true;
+    course.is_frozen = false;
     course.created_at = now;
     course.updated_at = now;
-    course._reserved = [0; 16];
+    course.schema_version = Course::SCHEMA_VERSION;
+    course.is_indexed = false;
+    course._reserved = [0; 9];
     course.bump = ctx.bumps.course;
+    course.active_enrollments = 0;
+    course.abandoned_enrollments = 0;
+    course.total_completion_time = 0;
+    
+    let course_key = course.key();
+    add_to_course_index(
+        course,
+        course_key,
+        &mut ctx.accounts.global_index,
+        &mut ctx.accounts.track_index,
+    )?;
+    
+    let stats = &mut ctx.accounts.course_stats;
+    stats.course = course.key();
+    stats.lesson_completions = [0; CourseStats::MAX_LESSONS];
+    stats.first_completion_at = None;
+    stats.last_completion_at = None;
+    stats.bump = ctx.bumps.course_stats;
+    
+    emit_cpi!(CourseCreated {
+        course: course.key(),
+        course_id: course_id.clone(),
+        creator: course.creator,
+        track_id: course.track_id,
+        track_level: course.track_level,
+        timestamp: now,
+    });
     
     msg!("Course created: {}", course_id);
     msg!("Creator: {}", course.creator);
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::CourseIndexPageCreated;
+
+/// Open a CourseIndex page (permissionless).
+/// Page N can only be opened once page N-1 is full, so clients read
+/// pages 0, 1, ... until one has room. Track scopes need the Track PDA.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(scope: u16, page: u16)]
+pub struct CreateCourseIndexPage<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Track the index is scoped to (required unless scope is GLOBAL)
+    #[account(
+        seeds = [Track::SEED, config.key().as_ref(), &scope.to_le_bytes()],
+        bump = track.bump,
+    )]
+    pub track: Option<Box<Account<'info, Track>>>,
+    
+    /// Previous page of the same index (required unless page 0)
+    #[account(
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &scope.to_le_bytes(),
+            &page.saturating_sub(1).to_le_bytes(),
+        ],
+        bump = previous_page.bump,
+    )]
+    pub previous_page: Option<Box<Account<'info, CourseIndex>>>,
+    
+    /// New CourseIndex page
+    #[account(
+        init,
+        payer = payer,
+        space = CourseIndex::SIZE,
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &scope.to_le_bytes(),
+            &page.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub course_index: Box<Account<'info, CourseIndex>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_course_index_page(
+    ctx: Context<CreateCourseIndexPage>,
+    scope: u16,
+    page: u16,
+) -> Result<()> {
+    require!(
+        scope == CourseIndex::GLOBAL || ctx.accounts.track.is_some(),
+        AcademyError::InvalidCourseIndexScope
+    );
+    
+    if page > 0 {
+        let previous = ctx
+            .accounts
+            .previous_page
+            .as_ref()
+            .ok_or(AcademyError::InvalidCourseIndexPage)?;
+        require!(previous.is_full(), AcademyError::InvalidCourseIndexPage);
+    }
+    
+    let index = &mut ctx.accounts.course_index;
+    index.scope = scope;
+    index.page = page;
+    index.entries = Vec::new();
+    index.bump = ctx.bumps.course_index;
+    
+    emit_cpi!(CourseIndexPageCreated {
+        course_index: index.key(),
+        scope,
+        page,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Course index page opened: scope={}, page={}", scope, page);
+    
+    Ok(())
+}
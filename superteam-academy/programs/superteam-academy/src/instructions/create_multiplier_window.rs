@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::MultiplierWindowCreated;
+
+/// Schedule a time-boxed XP multiplier window
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(window_id: u32)]
+pub struct CreateMultiplierWindow<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// MultiplierWindow PDA
+    #[account(
+        init,
+        payer = payer,
+        space = MultiplierWindow::SIZE,
+        seeds = [MultiplierWindow::SEED, config.key().as_ref(), &window_id.to_le_bytes()],
+        bump,
+    )]
+    pub multiplier_window: Account<'info, MultiplierWindow>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Params for create_multiplier_window
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateMultiplierWindowParams {
+    pub starts_at: i64,
+    pub ends_at: i64,
+    pub multiplier_bps: u16,
+    pub track_id: Option<u16>,
+    pub course: Option<Pubkey>,
+}
+
+pub fn create_multiplier_window(
+    ctx: Context<CreateMultiplierWindow>,
+    window_id: u32,
+    params: CreateMultiplierWindowParams,
+) -> Result<()> {
+    require!(
+        params.ends_at > params.starts_at,
+        AcademyError::InvalidMultiplierWindow
+    );
+    
+    require!(
+        params.multiplier_bps >= MultiplierWindow::BASE_BPS
+            && params.multiplier_bps <= MultiplierWindow::MAX_MULTIPLIER_BPS,
+        AcademyError::InvalidMultiplier
+    );
+    
+    let window = &mut ctx.accounts.multiplier_window;
+    
+    window.config = ctx.accounts.config.key();
+    window.window_id = window_id;
+    window.starts_at = params.starts_at;
+    window.ends_at = params.ends_at;
+    window.multiplier_bps = params.multiplier_bps;
+    window.track_id = params.track_id;
+    window.course = params.course;
+    window.created_at = Clock::get()?.unix_timestamp;
+    window.bump = ctx.bumps.multiplier_window;
+    
+    emit_cpi!(MultiplierWindowCreated {
+        window_id,
+        multiplier_bps: window.multiplier_bps,
+        starts_at: window.starts_at,
+        ends_at: window.ends_at,
+        track_id: window.track_id,
+        course: window.course,
+        timestamp: window.created_at,
+    });
+    
+    msg!(
+        "Multiplier window {}: {} bps from {} to {}",
+        window_id,
+        window.multiplier_bps,
+        window.starts_at,
+        window.ends_at
+    );
+    
+    Ok(())
+}
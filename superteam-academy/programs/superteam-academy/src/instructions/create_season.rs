@@ -1,11 +1,29 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_interface::{Token2022, token_interface::{Mint, TokenAccount}};
-use anchor_spl::token_2022;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_interface::{
+    Token2022,
+    initialize_mint2, InitializeMint2,
+    non_transferable_mint_initialize, NonTransferableMintInitialize,
+    permanent_delegate_initialize, PermanentDelegateInitialize,
+    metadata_pointer_initialize, MetadataPointerInitialize,
+    group_member_pointer_initialize, GroupMemberPointerInitialize,
+    token_metadata_initialize, TokenMetadataInitialize,
+    token_member_initialize, TokenMemberInitialize,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType,
+    state::Mint as MintState,
+};
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::SeasonCreated;
+use crate::utils::{
+    xp_mint_len, xp_mint_name, MAX_XP_URI_LEN, XP_GROUP_SEED, XP_MINT_EXTENSIONS, XP_SYMBOL,
+};
 
 /// Create a new season with Token-2022 XP mint
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(season: u16)]
 pub struct CreateSeason<'info> {
@@ -15,31 +33,53 @@ pub struct CreateSeason<'info> {
     /// Config PDA
     #[account(
         mut,
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = authority @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
-    /// Platform authority
+    /// Platform authority or admin council
+    #[account(
+        constraint = config.is_admin(authority.key) @ AcademyError::Unauthorized,
+    )]
     pub authority: Signer<'info>,
     
-    /// New XP mint for this season
+    /// New XP mint for this season (fresh keypair, allocated here)
+    #[account(mut)]
+    pub xp_mint: Signer<'info>,
+    
+    /// XP token group the new mint joins
+    /// CHECK: PDA address; Token-2022 validates the group on member init
+    #[account(
+        mut,
+        seeds = [XP_GROUP_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub xp_group: UncheckedAccount<'info>,
+    
+    /// Season archive PDA
     #[account(
         init,
         payer = payer,
-        mint::decimals = 0,
-        mint::authority = config,
+        space = Season::SIZE,
+        seeds = [Season::SEED, config.key().as_ref(), &season.to_le_bytes()],
+        bump,
     )]
-    pub xp_mint: InterfaceAccount<'info, Mint>,
+    pub season_record: Account<'info, Season>,
     
     pub token_program: Program<'info, Token2022>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn create_season(ctx: Context<CreateSeason>, season: u16) -> Result<()> {
-    let config = &mut ctx.accounts.config;
+pub fn create_season(
+    ctx: Context<CreateSeason>,
+    season: u16,
+    ends_at: Option<i64>,
+    metadata_uri: String,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
     
     // Season must be sequential
     require!(
@@ -52,17 +92,175 @@ pub fn create_season(ctx: Context<CreateSeason>, season: u16) -> Result<()> {
         require!(config.season_closed, AcademyError::SeasonNotClosed);
     }
     
-    // TODO: Initialize Token-2022 with NonTransferable and PermanentDelegate extensions
-    // This requires manual CPI calls to Token-2022 program
-    // For now, basic mint created - extensions to be added via separate ix or client-side
+    // Scheduled end must be in the future
+    if let Some(ends_at) = ends_at {
+        require!(ends_at > now, AcademyError::InvalidSeasonEnd);
+    }
+    
+    require!(
+        metadata_uri.len() <= MAX_XP_URI_LEN,
+        AcademyError::MetadataUriTooLong
+    );
+    
+    let config_key = config.key();
+    let config_info = config.to_account_info();
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[config.bump]];
+    let mint = ctx.accounts.xp_mint.to_account_info();
+    let group = ctx.accounts.xp_group.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    let name = xp_mint_name(season);
+    
+    // Allocate mint account sized for its fixed extensions; member and
+    // metadata are realloc'd by Token-2022, so rent covers the final size
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&XP_MINT_EXTENSIONS)?;
+    let full_space = xp_mint_len(
+        &XP_MINT_EXTENSIONS,
+        ExtensionType::TokenGroupMember,
+        name.clone(),
+        metadata_uri.clone(),
+    )?;
+    let lamports = ctx.accounts.rent.minimum_balance(full_space);
+    create_account(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: mint.clone(),
+            },
+        ),
+        lamports,
+        space as u64,
+        &token_program.key(),
+    )?;
+    
+    // Extensions must be initialized before the mint itself
+    // NonTransferable: XP is soulbound
+    non_transferable_mint_initialize(CpiContext::new(
+        token_program.clone(),
+        NonTransferableMintInitialize {
+            token_program_id: token_program.clone(),
+            mint: mint.clone(),
+        },
+    ))?;
+    
+    // PermanentDelegate: config PDA can burn XP (clawback, season resets)
+    permanent_delegate_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            PermanentDelegateInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        &config_key,
+    )?;
+    
+    // MetadataPointer: metadata lives on the mint itself
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        Some(config_key),
+        Some(mint.key()),
+    )?;
+    
+    // GroupMemberPointer: membership lives on the mint itself
+    group_member_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            GroupMemberPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: mint.clone(),
+            },
+        ),
+        Some(config_key),
+        Some(mint.key()),
+    )?;
+    
+    // Mint: 0 decimals, config PDA as mint authority, no freeze authority
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 { mint: mint.clone() },
+        ),
+        0,
+        &config_key,
+        None,
+    )?;
     
+    // On-mint metadata so wallets display the season's XP
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: token_program.clone(),
+                metadata: mint.clone(),
+                update_authority: config_info.clone(),
+                mint_authority: config_info.clone(),
+                mint: mint.clone(),
+            },
+            &[config_seeds],
+        ),
+        name,
+        XP_SYMBOL.to_string(),
+        metadata_uri,
+    )?;
+    
+    // Register as a member of the XP group
+    token_member_initialize(CpiContext::new_with_signer(
+        token_program.clone(),
+        TokenMemberInitialize {
+            program_id: token_program.clone(),
+            member: mint.clone(),
+            member_mint: mint.clone(),
+            member_mint_authority: config_info.clone(),
+            group,
+            group_update_authority: config_info,
+        },
+        &[config_seeds],
+    ))?;
+    
+    let config = &mut ctx.accounts.config;
     config.current_season = season;
-    config.current_mint = ctx.accounts.xp_mint.key();
+    config.current_mint = mint.key();
     config.season_closed = false;
-    config.season_started_at = Clock::get()?.unix_timestamp;
+    config.season_started_at = now;
+    config.season_ends_at = ends_at;
+    
+    let season_record = &mut ctx.accounts.season_record;
+    season_record.season = season;
+    season_record.mint = mint.key();
+    season_record.started_at = now;
+    season_record.ends_at = ends_at;
+    season_record.ended_at = None;
+    season_record.total_xp_minted = 0;
+    season_record.total_xp_revoked = 0;
+    season_record.lessons_completed = 0;
+    season_record.courses_finalized = 0;
+    season_record.is_closed = false;
+    season_record.leaderboard_root = None;
+    season_record.reward_mint = Pubkey::default();
+    season_record.rewards_claimed = 0;
+    season_record.rewards_paid = 0;
+    season_record._reserved = [0; 15];
+    season_record.bump = ctx.bumps.season_record;
+    
+    emit_cpi!(SeasonCreated {
+        season,
+        mint: mint.key(),
+        timestamp: now,
+    });
     
     msg!("Season {} created", season);
-    msg!("XP Mint: {}", ctx.accounts.xp_mint.key());
+    msg!("XP Mint: {}", mint.key());
+    if let Some(ends_at) = ends_at {
+        msg!("Ends at: {}", ends_at);
+    }
     
     Ok(())
 }
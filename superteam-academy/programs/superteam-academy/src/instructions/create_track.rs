@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::TrackCreated;
+
+/// Register a learning track
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(track_id: u16)]
+pub struct CreateTrack<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// Track PDA
+    #[account(
+        init,
+        payer = payer,
+        space = Track::SIZE,
+        seeds = [Track::SEED, config.key().as_ref(), &track_id.to_le_bytes()],
+        bump,
+    )]
+    pub track: Account<'info, Track>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Params for create_track
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CreateTrackParams {
+    pub name: String,
+    pub description_uri: String,
+    pub max_level: u8,
+    pub level_names: Vec<String>,
+}
+
+pub fn create_track(
+    ctx: Context<CreateTrack>,
+    track_id: u16,
+    params: CreateTrackParams,
+) -> Result<()> {
+    require!(track_id != CourseIndex::GLOBAL, AcademyError::ReservedTrackId);
+    Track::validate(
+        &params.name,
+        &params.description_uri,
+        params.max_level,
+        &params.level_names,
+    )?;
+    
+    let track = &mut ctx.accounts.track;
+    let now = Clock::get()?.unix_timestamp;
+    
+    track.track_id = track_id;
+    track.name = params.name;
+    track.description_uri = params.description_uri;
+    track.max_level = params.max_level;
+    track.level_names = params.level_names;
+    track.collection = Pubkey::default();
+    track.is_active = true;
+    track.created_at = now;
+    track.updated_at = now;
+    track._reserved = [0; 16];
+    track.bump = ctx.bumps.track;
+    
+    emit_cpi!(TrackCreated {
+        track_id,
+        name: track.name.clone(),
+        max_level: track.max_level,
+        timestamp: now,
+    });
+    
+    msg!("Track {} created: {}", track_id, track.name);
+    
+    Ok(())
+}
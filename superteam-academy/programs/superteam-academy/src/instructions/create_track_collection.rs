@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use mpl_core::instructions::CreateCollectionV2CpiBuilder;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::TrackCollectionCreated;
+
+/// Create the Metaplex Core collection holding a track's credentials.
+/// The config PDA is the collection's update authority.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateTrackCollection<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA (collection update authority)
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// Track PDA
+    #[account(
+        mut,
+        seeds = [Track::SEED, config.key().as_ref(), &track.track_id.to_le_bytes()],
+        bump = track.bump,
+        constraint = track.collection == Pubkey::default() @ AcademyError::TrackCollectionAlreadySet,
+    )]
+    pub track: Account<'info, Track>,
+    
+    /// New collection account
+    #[account(mut)]
+    pub collection: Signer<'info>,
+    
+    /// Metaplex Core program
+    /// CHECK: Address checked
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_track_collection(
+    ctx: Context<CreateTrackCollection>,
+    metadata_uri: String,
+) -> Result<()> {
+    require!(
+        metadata_uri.len() <= Track::MAX_URI_LEN,
+        AcademyError::MetadataUriTooLong
+    );
+    
+    let config = &ctx.accounts.config;
+    let config_info = config.to_account_info();
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[config.bump]];
+    let now = Clock::get()?.unix_timestamp;
+    
+    CreateCollectionV2CpiBuilder::new(&ctx.accounts.mpl_core_program)
+        .collection(&ctx.accounts.collection.to_account_info())
+        .update_authority(Some(&config_info))
+        .payer(&ctx.accounts.payer.to_account_info())
+        .system_program(&ctx.accounts.system_program.to_account_info())
+        .name(ctx.accounts.track.name.clone())
+        .uri(metadata_uri)
+        .invoke_signed(&[config_seeds])?;
+    
+    let track = &mut ctx.accounts.track;
+    track.collection = ctx.accounts.collection.key();
+    track.updated_at = now;
+    
+    emit_cpi!(TrackCollectionCreated {
+        track_id: track.track_id,
+        collection: track.collection,
+        timestamp: now,
+    });
+    
+    msg!("Track {} collection: {}", track.track_id, track.collection);
+    
+    Ok(())
+}
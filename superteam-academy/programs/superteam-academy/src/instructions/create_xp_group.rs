@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, CreateAccount};
+use anchor_spl::token_interface::{
+    Token2022,
+    initialize_mint2, InitializeMint2,
+    group_pointer_initialize, GroupPointerInitialize,
+    metadata_pointer_initialize, MetadataPointerInitialize,
+    token_metadata_initialize, TokenMetadataInitialize,
+    token_group_initialize, TokenGroupInitialize,
+};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType,
+    state::Mint as MintState,
+};
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::XpGroupCreated;
+use crate::utils::{
+    xp_mint_len, MAX_XP_URI_LEN, XP_GROUP_EXTENSIONS, XP_GROUP_NAME, XP_GROUP_SEED, XP_SYMBOL,
+};
+
+/// Create the XP token group (one-time).
+/// Every season XP mint is registered as a member of this group.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateXpGroup<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA (group update authority)
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// XP group mint PDA
+    /// CHECK: allocated and initialized as a Token-2022 mint here
+    #[account(
+        mut,
+        seeds = [XP_GROUP_SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub xp_group: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn create_xp_group(ctx: Context<CreateXpGroup>, metadata_uri: String) -> Result<()> {
+    require!(
+        metadata_uri.len() <= MAX_XP_URI_LEN,
+        AcademyError::MetadataUriTooLong
+    );
+    
+    let config = &ctx.accounts.config;
+    let config_key = config.key();
+    let config_info = config.to_account_info();
+    let group = ctx.accounts.xp_group.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+    
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[config.bump]];
+    let group_seeds = &[XP_GROUP_SEED, config_key.as_ref(), &[ctx.bumps.xp_group]];
+    
+    // Allocate for the fixed extensions, fund rent for group + metadata
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&XP_GROUP_EXTENSIONS)?;
+    let full_space = xp_mint_len(
+        &XP_GROUP_EXTENSIONS,
+        ExtensionType::TokenGroup,
+        XP_GROUP_NAME.to_string(),
+        metadata_uri.clone(),
+    )?;
+    let lamports = ctx.accounts.rent.minimum_balance(full_space);
+    create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: group.clone(),
+            },
+            &[group_seeds],
+        ),
+        lamports,
+        space as u64,
+        &token_program.key(),
+    )?;
+    
+    // GroupPointer: group state lives on the mint itself
+    group_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            GroupPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: group.clone(),
+            },
+        ),
+        Some(config_key),
+        Some(group.key()),
+    )?;
+    
+    metadata_pointer_initialize(
+        CpiContext::new(
+            token_program.clone(),
+            MetadataPointerInitialize {
+                token_program_id: token_program.clone(),
+                mint: group.clone(),
+            },
+        ),
+        Some(config_key),
+        Some(group.key()),
+    )?;
+    
+    // Group mint never mints; config PDA holds authority only to sign group ops
+    initialize_mint2(
+        CpiContext::new(
+            token_program.clone(),
+            InitializeMint2 { mint: group.clone() },
+        ),
+        0,
+        &config_key,
+        None,
+    )?;
+    
+    token_metadata_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenMetadataInitialize {
+                program_id: token_program.clone(),
+                metadata: group.clone(),
+                update_authority: config_info.clone(),
+                mint_authority: config_info.clone(),
+                mint: group.clone(),
+            },
+            &[config_seeds],
+        ),
+        XP_GROUP_NAME.to_string(),
+        XP_SYMBOL.to_string(),
+        metadata_uri,
+    )?;
+    
+    // One member per season
+    token_group_initialize(
+        CpiContext::new_with_signer(
+            token_program.clone(),
+            TokenGroupInitialize {
+                program_id: token_program.clone(),
+                group: group.clone(),
+                mint: group.clone(),
+                mint_authority: config_info,
+            },
+            &[config_seeds],
+        ),
+        Some(config_key),
+        u16::MAX as u64,
+    )?;
+    
+    emit_cpi!(XpGroupCreated {
+        group: group.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("XP group created: {}", group.key());
+    
+    Ok(())
+}
@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::Enrolled;
 
 /// Enroll in a course
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(course_id: String)]
 pub struct Enroll<'info> {
@@ -13,16 +15,24 @@ pub struct Enroll<'info> {
     /// Learner wallet
     pub learner: Signer<'info>,
     
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
     /// LearnerProfile PDA
     #[account(
-        seeds = [LearnerProfile::SEED, learner.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
         bump = learner_profile.bump,
     )]
     pub learner_profile: Account<'info, LearnerProfile>,
     
     /// Course PDA
     #[account(
-        seeds = Course::seeds(&course.course_id),
+        mut,
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
@@ -33,7 +43,8 @@ pub struct Enroll<'info> {
         payer = payer,
         space = Enrollment::SIZE,
         seeds = [
-            b"enrollment",
+            b"enrollment".as_ref(),
+            &config.scope_seed(&config.key()),
             course_id.as_bytes(),
             learner.key().as_ref(),
         ],
@@ -48,10 +59,13 @@ pub struct Enroll<'info> {
 }
 
 pub fn enroll(ctx: Context<Enroll>, course_id: String) -> Result<()> {
-    let course = &ctx.accounts.course;
+    let course = &mut ctx.accounts.course;
     let enrollment = &mut ctx.accounts.enrollment;
     let now = Clock::get()?.unix_timestamp;
     
+    ctx.accounts.config.require_not_paused(Config::PAUSE_ENROLLMENT)?;
+    course.require_not_frozen()?;
+    
     // Course must be active
     require!(course.is_active, AcademyError::CourseNotActive);
     
@@ -69,17 +83,27 @@ pub fn enroll(ctx: Context<Enroll>, course_id: String) -> Result<()> {
         );
     }
     
+    course.record_enrollment()?;
+    
     // Initialize enrollment
-    enrollment.course = ctx.accounts.course.key();
+    enrollment.course = course.key();
     enrollment.enrolled_version = course.version;
     enrollment.enrolled_at = now;
     enrollment.completed_at = None;
     enrollment.lesson_flags = [0; 4];
     enrollment.credential_asset = None;
     enrollment.bonus_claimed = false;
-    enrollment._reserved = [0; 7];
+    enrollment.schema_version = Enrollment::SCHEMA_VERSION;
+    enrollment._reserved = [0; 6];
     enrollment.bump = ctx.bumps.enrollment;
     
+    emit_cpi!(Enrolled {
+        learner: ctx.accounts.learner.key(),
+        course: enrollment.course,
+        course_version: enrollment.enrolled_version,
+        timestamp: now,
+    });
+    
     msg!("Enrolled: {} in {}", ctx.accounts.learner.key(), course_id);
     
     Ok(())
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AdminProposalExecuted;
+use crate::program::SuperteamAcademy;
+
+/// Execute an approved admin proposal (permissionless).
+/// The council PDA signs as `authority` of the target instruction, whose
+/// accounts are passed in order as remaining accounts.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteAdminProposal<'info> {
+    /// Anyone may execute
+    pub executor: Signer<'info>,
+    
+    /// AdminCouncil PDA (Config.council)
+    #[account(
+        seeds = [AdminCouncil::SEED, admin_council.config.as_ref()],
+        bump = admin_council.bump,
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+    
+    /// AdminProposal PDA
+    #[account(
+        mut,
+        seeds = [AdminProposal::SEED, admin_council.key().as_ref(), &proposal.proposal_id.to_le_bytes()],
+        bump = proposal.bump,
+        has_one = proposer,
+        close = proposer,
+    )]
+    pub proposal: Account<'info, AdminProposal>,
+    
+    /// Proposer (rent refund)
+    /// CHECK: Verified against proposal.proposer
+    #[account(mut)]
+    pub proposer: AccountInfo<'info>,
+    
+    pub academy_program: Program<'info, SuperteamAcademy>,
+}
+
+pub fn execute_admin_proposal<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteAdminProposal<'info>>,
+) -> Result<()> {
+    let council = &ctx.accounts.admin_council;
+    let proposal = &ctx.accounts.proposal;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(now < proposal.expires_at, AcademyError::ProposalExpired);
+    require!(
+        proposal.members_epoch == council.members_epoch,
+        AcademyError::StaleProposal
+    );
+    require!(
+        proposal.approval_count() >= council.threshold,
+        AcademyError::ProposalThresholdNotMet
+    );
+    
+    let council_key = council.key();
+    let accounts = ctx
+        .remaining_accounts
+        .iter()
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == council_key,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let ix = Instruction {
+        program_id: crate::ID,
+        accounts,
+        data: proposal.action.data(),
+    };
+    
+    let mut account_infos = ctx.remaining_accounts.to_vec();
+    account_infos.push(ctx.accounts.academy_program.to_account_info());
+    let council_seeds = &[AdminCouncil::SEED, council.config.as_ref(), &[council.bump]];
+    invoke_signed(&ix, &account_infos, &[council_seeds])?;
+    
+    emit_cpi!(AdminProposalExecuted {
+        proposal_id: proposal.proposal_id,
+        executed_by: ctx.accounts.executor.key(),
+        timestamp: now,
+    });
+    
+    msg!("Admin proposal {} executed", proposal.proposal_id);
+    
+    Ok(())
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::{ConfigChangeExecuted, ConfigUpdated, SignerRotationStarted, SignerUpdated};
+
+/// Apply a queued config change after its delay (permissionless)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ExecuteConfigChange<'info> {
+    /// Anyone may execute
+    pub executor: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Queued change
+    #[account(
+        mut,
+        seeds = [PendingConfigChange::SEED, config.key().as_ref(), &pending_change.change_id.to_le_bytes()],
+        bump = pending_change.bump,
+        has_one = rent_payer,
+        close = rent_payer,
+    )]
+    pub pending_change: Account<'info, PendingConfigChange>,
+    
+    /// Rent refund destination
+    /// CHECK: Verified against pending_change.rent_payer
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+    
+    /// SignerRegistry PDA (required for queued signer changes)
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Option<Account<'info, SignerRegistry>>,
+}
+
+pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+    let pending = &ctx.accounts.pending_change;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        now >= pending.execute_after,
+        AcademyError::TimelockNotElapsed
+    );
+    
+    match pending.change.clone() {
+        ConfigChange::Config(params) => {
+            for field in params.apply(&mut ctx.accounts.config)? {
+                emit_cpi!(ConfigUpdated {
+                    field: field.to_string(),
+                    timestamp: now,
+                });
+            }
+        }
+        ConfigChange::SetSigner(params) => {
+            let registry = ctx
+                .accounts
+                .signer_registry
+                .as_mut()
+                .ok_or(AcademyError::SignerRegistryRequired)?;
+            registry.set_signer(&params, now)?;
+            
+            emit_cpi!(SignerUpdated {
+                signer: params.signer,
+                permissions: params.permissions,
+                daily_xp_quota: params.daily_xp_quota,
+                expires_at: params.expires_at,
+                timestamp: now,
+            });
+        }
+        ConfigChange::RotateSigner {
+            old_signer,
+            next_signer,
+            rotation_deadline,
+        } => {
+            let registry = ctx
+                .accounts
+                .signer_registry
+                .as_mut()
+                .ok_or(AcademyError::SignerRegistryRequired)?;
+            registry.rotate(&old_signer, next_signer, rotation_deadline, now)?;
+            
+            emit_cpi!(SignerRotationStarted {
+                old_signer,
+                next_signer,
+                rotation_deadline,
+                timestamp: now,
+            });
+        }
+    }
+    
+    emit_cpi!(ConfigChangeExecuted {
+        change_id: pending.change_id,
+        executed_by: ctx.accounts.executor.key(),
+        timestamp: now,
+    });
+    
+    msg!("Config change {} executed", pending.change_id);
+    
+    Ok(())
+}
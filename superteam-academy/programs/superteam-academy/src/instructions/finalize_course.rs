@@ -3,8 +3,11 @@ use anchor_spl::token_interface::{Token2022, TokenAccount, Mint, MintTo};
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::{CourseFinalized, LevelUp};
+use crate::utils::credit_learner_xp;
 
 /// Finalize entire course: verify completion, award creator XP
+#[event_cpi]
 #[derive(Accounts)]
 pub struct FinalizeCourse<'info> {
     /// Backend signer
@@ -12,25 +15,41 @@ pub struct FinalizeCourse<'info> {
     
     /// Config PDA
     #[account(
-        mut,
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = backend_signer @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
+    /// Backend signer registry
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
     /// Course PDA
     #[account(
         mut,
-        seeds = Course::seeds(&course.course_id),
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
     
     /// Course creator
-    /// CHECK: Used for token account
+    /// CHECK: Must be the course creator
+    #[account(address = course.creator @ AcademyError::Unauthorized)]
     pub creator: AccountInfo<'info>,
     
+    /// Creator's LearnerProfile (lifetime XP tracked when provided; a
+    /// creator without a profile must not block finalization)
+    #[account(
+        mut,
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), creator.key().as_ref()],
+        bump = creator_profile.bump,
+    )]
+    pub creator_profile: Option<Account<'info, LearnerProfile>>,
+    
     /// Learner wallet
     /// CHECK: Used for PDA
     pub learner: AccountInfo<'info>,
@@ -40,6 +59,7 @@ pub struct FinalizeCourse<'info> {
         mut,
         seeds = [
             b"enrollment",
+            &config.scope_seed(&config.key()),
             course.course_id.as_bytes(),
             learner.key().as_ref(),
         ],
@@ -47,6 +67,14 @@ pub struct FinalizeCourse<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
     
+    /// Season archive PDA for the current season
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &config.current_season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
     /// XP Mint (Token-2022)
     #[account(
         mut,
@@ -65,7 +93,7 @@ pub struct FinalizeCourse<'info> {
     /// Config PDA as mint authority
     /// CHECK: Derived from config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
     )]
     pub config_pda: AccountInfo<'info>,
@@ -78,6 +106,17 @@ pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
     let enrollment = &mut ctx.accounts.enrollment;
     let now = Clock::get()?.unix_timestamp;
     
+    // Check season not closed or past its scheduled end
+    ctx.accounts.config.require_season_active(now)?;
+    ctx.accounts.config.require_not_paused(Config::PAUSE_LESSONS)?;
+    course.require_not_frozen()?;
+    
+    let signer_index = ctx.accounts.signer_registry.check(
+        ctx.accounts.backend_signer.key,
+        SignerRegistry::PERM_FINALIZE,
+        now,
+    )?;
+    
     // Must not already be finalized
     require!(
         enrollment.completed_at.is_none(),
@@ -93,17 +132,23 @@ pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
     // Mark as completed
     enrollment.completed_at = Some(now);
     
-    // Increment course completions
-    course.total_completions = course
-        .total_completions
-        .checked_add(1)
-        .ok_or(AcademyError::Overflow)?;
+    // Course completions and time-to-complete
+    course.record_completion(enrollment.enrolled_at, now)?;
+    
+    // Season totals
+    ctx.accounts.season_record.record_course_finalized()?;
     
     // Award creator XP if threshold met
+    let mut creator_xp_awarded = 0u32;
     if course.total_completions >= course.min_completions_for_reward as u32 {
         let creator_xp = course.creator_reward_xp as u64;
         if creator_xp > 0 {
-            let config_seeds = &[Config::SEED, &[ctx.accounts.config.bump]];
+            ctx.accounts
+                .signer_registry
+                .charge_quota(signer_index, course.creator_reward_xp, now)?;
+            
+            let academy_seed = Config::academy_seed(ctx.accounts.config.academy_id);
+            let config_seeds = &[Config::SEED, &academy_seed, &[ctx.accounts.config.bump]];
             let signer_seeds = &[&config_seeds[..]];
             
             let cpi_accounts = MintTo {
@@ -118,6 +163,28 @@ pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
             );
             anchor_spl::token_interface::mint_to(cpi_ctx, creator_xp)?;
             
+            ctx.accounts.season_record.record_xp(creator_xp)?;
+            creator_xp_awarded = course.creator_reward_xp;
+            
+            // Lifetime/season XP and level
+            if let Some(creator_profile) = ctx.accounts.creator_profile.as_mut() {
+                let config = &ctx.accounts.config;
+                if let Some((old_level, new_level)) = credit_learner_xp(
+                    creator_profile,
+                    config,
+                    creator_xp,
+                    config.current_season,
+                )? {
+                    emit_cpi!(LevelUp {
+                        learner: ctx.accounts.creator.key(),
+                        old_level,
+                        new_level,
+                        lifetime_xp: creator_profile.lifetime_xp,
+                        timestamp: now,
+                    });
+                }
+            }
+            
             msg!(
                 "Creator reward: {} XP to {}",
                 creator_xp,
@@ -126,6 +193,18 @@ pub fn finalize_course(ctx: Context<FinalizeCourse>) -> Result<()> {
         }
     }
     
+    let total_xp = (course.lesson_count as u32)
+        .checked_mul(course.xp_per_lesson)
+        .ok_or(AcademyError::Overflow)?;
+    emit_cpi!(CourseFinalized {
+        learner: ctx.accounts.learner.key(),
+        course: course.key(),
+        total_xp,
+        creator: ctx.accounts.creator.key(),
+        creator_xp: creator_xp_awarded,
+        timestamp: now,
+    });
+    
     msg!(
         "Course finalized: {} by {}",
         course.course_id,
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::CourseIndexed;
+use crate::utils::add_to_course_index;
+
+/// List a course created before CourseIndex existed
+#[event_cpi]
+#[derive(Accounts)]
+pub struct IndexCourse<'info> {
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// Course PDA
+    #[account(
+        mut,
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+    
+    /// Open page of the global CourseIndex
+    #[account(
+        mut,
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &CourseIndex::GLOBAL.to_le_bytes(),
+            &global_index.page.to_le_bytes(),
+        ],
+        bump = global_index.bump,
+    )]
+    pub global_index: Box<Account<'info, CourseIndex>>,
+    
+    /// Open page of the course's track CourseIndex
+    #[account(
+        mut,
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &course.track_id.to_le_bytes(),
+            &track_index.page.to_le_bytes(),
+        ],
+        bump = track_index.bump,
+    )]
+    pub track_index: Box<Account<'info, CourseIndex>>,
+}
+
+pub fn index_course(ctx: Context<IndexCourse>) -> Result<()> {
+    let course_key = ctx.accounts.course.key();
+    add_to_course_index(
+        &mut ctx.accounts.course,
+        course_key,
+        &mut ctx.accounts.global_index,
+        &mut ctx.accounts.track_index,
+    )?;
+    
+    let course = &ctx.accounts.course;
+    emit_cpi!(CourseIndexed {
+        course: course_key,
+        global_index_page: course.global_index_page,
+        track_index_page: course.track_index_page,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Course indexed: {}", ctx.accounts.course.course_id);
+    
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AdminCouncilCreated;
+
+/// Create the M-of-N admin council
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitAdminCouncil<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// AdminCouncil PDA
+    #[account(
+        init,
+        payer = payer,
+        space = AdminCouncil::SIZE,
+        seeds = [AdminCouncil::SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_admin_council(
+    ctx: Context<InitAdminCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    require!(
+        AdminCouncil::is_valid(&members, threshold),
+        AcademyError::InvalidCouncil
+    );
+    
+    let council = &mut ctx.accounts.admin_council;
+    council.config = ctx.accounts.config.key();
+    council.members = members;
+    council.threshold = threshold;
+    council.next_proposal_id = 0;
+    council.members_epoch = 0;
+    council.bump = ctx.bumps.admin_council;
+    
+    ctx.accounts.config.council = council.key();
+    
+    emit_cpi!(AdminCouncilCreated {
+        council: council.key(),
+        members: council.members.clone(),
+        threshold,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Admin council created: {}", council.key());
+    msg!("Threshold: {} of {}", threshold, council.members.len());
+    
+    Ok(())
+}
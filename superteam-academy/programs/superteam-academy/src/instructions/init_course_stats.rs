@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::CourseStatsInitialized;
+
+/// Create the lesson funnel for a course that predates CourseStats (permissionless)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitCourseStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Course PDA
+    #[account(
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+    
+    /// CourseStats PDA
+    #[account(
+        init,
+        payer = payer,
+        space = CourseStats::SIZE,
+        seeds = [CourseStats::SEED, course.key().as_ref()],
+        bump,
+    )]
+    pub course_stats: Box<Account<'info, CourseStats>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_course_stats(ctx: Context<InitCourseStats>) -> Result<()> {
+    let stats = &mut ctx.accounts.course_stats;
+    stats.course = ctx.accounts.course.key();
+    stats.lesson_completions = [0; CourseStats::MAX_LESSONS];
+    stats.first_completion_at = None;
+    stats.last_completion_at = None;
+    stats.bump = ctx.bumps.course_stats;
+    
+    emit_cpi!(CourseStatsInitialized {
+        course: stats.course,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Course stats created for: {}", ctx.accounts.course.course_id);
+    
+    Ok(())
+}
@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::LearnerInitialized;
 
 /// Initialize learner profile
+#[event_cpi]
 #[derive(Accounts)]
 pub struct InitLearner<'info> {
     #[account(mut)]
@@ -12,12 +14,19 @@ pub struct InitLearner<'info> {
     /// Learner wallet
     pub learner: Signer<'info>,
     
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
     /// LearnerProfile PDA
     #[account(
         init,
         payer = payer,
         space = LearnerProfile::SIZE,
-        seeds = [LearnerProfile::SEED, learner.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
         bump,
     )]
     pub profile: Account<'info, LearnerProfile>,
@@ -39,9 +48,19 @@ pub fn init_learner(ctx: Context<InitLearner>) -> Result<()> {
     profile.last_xp_day = 0;
     profile.referral_count = 0;
     profile.has_referrer = false;
-    profile._reserved = [0; 16];
+    profile.lifetime_xp = 0;
+    profile.season_xp = 0;
+    profile.xp_season = 0;
+    profile.level = 0;
+    profile.schema_version = LearnerProfile::SCHEMA_VERSION;
+    profile._reserved = [0; 11];
     profile.bump = ctx.bumps.profile;
     
+    emit_cpi!(LearnerInitialized {
+        learner: profile.authority,
+        timestamp: now,
+    });
+    
     msg!("Learner initialized: {}", profile.authority);
     
     Ok(())
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::SignerRegistryInitialized;
+
+/// Create the signer registry, seeded with the config backend signer
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitSignerRegistry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// SignerRegistry PDA
+    #[account(
+        init,
+        payer = payer,
+        space = SignerRegistry::SIZE,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_signer_registry(ctx: Context<InitSignerRegistry>) -> Result<()> {
+    let backend_signer = ctx.accounts.config.backend_signer;
+    let registry = &mut ctx.accounts.signer_registry;
+    
+    // Keep the existing backend working until its roles are narrowed
+    registry.signers = vec![SignerEntry {
+        signer: backend_signer,
+        permissions: SignerRegistry::PERM_ALL,
+        daily_xp_quota: 0,
+        xp_today: 0,
+        quota_day: 0,
+        expires_at: None,
+    }];
+    registry.bump = ctx.bumps.signer_registry;
+    
+    emit_cpi!(SignerRegistryInitialized {
+        signer_registry: registry.key(),
+        backend_signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Signer registry initialized");
+    msg!("Backend signer: {}", backend_signer);
+    
+    Ok(())
+}
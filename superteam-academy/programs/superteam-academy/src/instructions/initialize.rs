@@ -9,9 +9,12 @@ use anchor_spl::token_2022::spl_token_2022::extension::{
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::AcademyInitialized;
 
-/// Initialize the platform
+/// Initialize an academy
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(params: InitializeParams)]
 pub struct Initialize<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
@@ -27,7 +30,7 @@ pub struct Initialize<'info> {
         init,
         payer = payer,
         space = Config::SIZE,
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(params.academy_id)],
         bump,
     )]
     pub config: Account<'info, Config>,
@@ -39,25 +42,47 @@ pub struct Initialize<'info> {
 /// Params for initialize
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeParams {
+    pub academy_id: u16,
     pub max_daily_xp: u32,
     pub max_achievement_xp: u32,
+    pub level_base_xp: u32,
+    pub level_growth_bps: u16,
 }
 
 pub fn initialize(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
     let config = &mut ctx.accounts.config;
     
+    config.academy_id = params.academy_id;
     config.authority = ctx.accounts.authority.key();
+    config.pending_authority = None;
     config.backend_signer = ctx.accounts.backend_signer.key();
     config.current_season = 0;
     config.current_mint = Pubkey::default();
     config.season_closed = true; // Will be set to false when first season created
     config.season_started_at = 0;
+    config.season_ends_at = None;
     config.max_daily_xp = params.max_daily_xp;
     config.max_achievement_xp = params.max_achievement_xp;
-    config._reserved = [0; 32];
+    config.level_base_xp = params.level_base_xp;
+    config.level_growth_bps = params.level_growth_bps;
+    config.pauser = ctx.accounts.authority.key();
+    config.council = Pubkey::default();
+    config.paused = 0;
+    config.config_timelock = 0;
+    config.next_config_change_id = 0;
+    config.schema_version = Config::SCHEMA_VERSION;
+    config._reserved = [0; 10];
     config.bump = ctx.bumps.config;
     
-    msg!("Platform initialized");
+    emit_cpi!(AcademyInitialized {
+        config: config.key(),
+        academy_id: config.academy_id,
+        authority: config.authority,
+        backend_signer: config.backend_signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Academy {} initialized", config.academy_id);
     msg!("Authority: {}", config.authority);
     msg!("Backend signer: {}", config.backend_signer);
     
@@ -2,8 +2,9 @@ use anchor_lang::prelude::*;
 use mpl_core::instructions::CreateV2CpiBuilder;
 use mpl_core::instructions::UpdateV1CpiBuilder;
 use mpl_core::instructions::UpdatePluginV1CpiBuilder;
+use mpl_core::fetch_asset_plugin;
 use mpl_core::types::{
-    PluginAuthorityPair, Plugin,
+    PluginAuthorityPair, Plugin, PluginType,
     PermanentFreezeDelegate, Attributes, Attribute,
     PluginAuthority,
     UpdateAuthority,
@@ -12,8 +13,12 @@ use mpl_core::types::{
 use crate::state::*;
 use crate::error::AcademyError;
 use crate::events::CredentialIssued;
+use crate::utils::{
+    credential_level, record_credential_completion, upsert_attribute, CREDENTIAL_LEVEL_KEY,
+};
 
 /// Issue or upgrade credential NFT via Metaplex Core
+#[event_cpi]
 #[derive(Accounts)]
 #[instruction(metadata_uri: String)]
 pub struct IssueCredential<'info> {
@@ -25,20 +30,34 @@ pub struct IssueCredential<'info> {
     
     /// Config PDA
     #[account(
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = backend_signer @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
+    /// Backend signer registry
+    #[account(
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
     /// Course PDA
     #[account(
         mut,
-        seeds = Course::seeds(&course.course_id),
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
         bump = course.bump,
     )]
     pub course: Account<'info, Course>,
     
+    /// Track registry entry for the course
+    #[account(
+        seeds = [Track::SEED, config.key().as_ref(), &course.track_id.to_le_bytes()],
+        bump = track.bump,
+        constraint = track.collection != Pubkey::default() @ AcademyError::TrackCollectionNotSet,
+    )]
+    pub track: Account<'info, Track>,
+    
     /// Learner wallet
     /// CHECK: Used for NFT owner
     pub learner: AccountInfo<'info>,
@@ -48,6 +67,7 @@ pub struct IssueCredential<'info> {
         mut,
         seeds = [
             b"enrollment",
+            &config.scope_seed(&config.key()),
             course.course_id.as_bytes(),
             learner.key().as_ref(),
         ],
@@ -55,9 +75,27 @@ pub struct IssueCredential<'info> {
     )]
     pub enrollment: Account<'info, Enrollment>,
     
+    /// Learner's progress through the course's track
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = TrackProgress::SIZE,
+        seeds = [
+            TrackProgress::SEED,
+            config.key().as_ref(),
+            learner.key().as_ref(),
+            &course.track_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub track_progress: Box<Account<'info, TrackProgress>>,
+    
     /// Track collection NFT
-    /// CHECK: Verified by MetaPlex Core, PDA seeds checked off-chain
-    #[account(mut)]
+    /// CHECK: Must be the collection registered on the track
+    #[account(
+        mut,
+        address = track.collection @ AcademyError::InvalidTrackCollection,
+    )]
     pub track_collection: AccountInfo<'info>,
     
     /// Credential asset (new or existing)
@@ -66,56 +104,91 @@ pub struct IssueCredential<'info> {
     pub credential_asset: AccountInfo<'info>,
     
     /// Metaplex Core program
-    /// CHECK: Metaplex Core
+    /// CHECK: Address checked
+    #[account(address = mpl_core::ID)]
     pub mpl_core_program: AccountInfo<'info>,
     pub system_program: Program<'info, System>,
 }
 
-/// Level names for credential displayn level_name(level: u8) -> &'static str {
-    match level {
-        0 => "Beginner",
-        1 => "Beginner",
-        2 => "Intermediate",
-        3 => "Advanced",
-        _ => "Expert",
-    }
-}
-
-/// Track names (hardcoded V1)
-fn track_name(track_id: u16) -> String {
-    match track_id {
-        0 => "Standalone".to_string(),
-        1 => "Anchor Framework".to_string(),
-        2 => "Rust for Solana".to_string(),
-        3 => "DeFi Development".to_string(),
-        4 => "Program Security".to_string(),
-        _ => format!("Track {}", track_id),
-    }
-}
-
 pub fn issue_credential(
     ctx: Context<IssueCredential>,
     metadata_uri: String,
 ) -> Result<()> {
     let course = &ctx.accounts.course;
     let enrollment = &mut ctx.accounts.enrollment;
+    let progress = &mut ctx.accounts.track_progress;
     let config = &ctx.accounts.config;
     let now = Clock::get()?.unix_timestamp;
     
+    ctx.accounts.signer_registry.check(
+        ctx.accounts.backend_signer.key,
+        SignerRegistry::PERM_CREDENTIALS,
+        now,
+    )?;
+    
+    config.require_not_paused(Config::PAUSE_CREDENTIALS)?;
+    course.require_not_frozen()?;
+    
     // Must be finalized
-    require!(
-        enrollment.completed_at.is_some(),
-        AcademyError::CourseNotFinalized
-    );
+    let completed_at = enrollment
+        .completed_at
+        .ok_or(AcademyError::CourseNotFinalized)?;
+    
+    if progress.learner == Pubkey::default() {
+        progress.init(
+            ctx.accounts.learner.key(),
+            course.track_id,
+            enrollment.credential_asset,
+            ctx.bumps.track_progress,
+        );
+    }
     
-    let is_new = enrollment.credential_asset.is_none();
-    let config_seeds = &[ Config::SEED, &[config.bump] ];
+    // One credential per learner per track
+    if let Some(asset_key) = progress.credential_asset {
+        require!(
+            ctx.accounts.credential_asset.key() == asset_key,
+            AcademyError::InvalidCredentialAsset
+        );
+    }
+    
+    require!(progress.revoked_at.is_none(), AcademyError::CredentialRevoked);
+    progress.record_course(course.key(), course.track_level, now)?;
+    let courses_completed = progress.courses_completed();
+    let current_level = progress.highest_level;
+    
+    let is_new = progress.credential_asset.is_none();
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[ Config::SEED, &academy_seed, &[config.bump] ];
     let config_signer_seeds = &[&config_seeds[..]];
-    let track = track_name(course.track_id);
-    let level = level_name(course.track_level);
+    let track = ctx.accounts.track.name.clone();
+    let level = ctx.accounts.track.level_name(current_level);
     let display_name = format!("{} — {}", track, level);
     
     if is_new {
+        let mut attribute_list = vec![
+            Attribute { 
+                key: "track_id".into(), 
+                value: course.track_id.to_string(),
+            },
+            Attribute { 
+                key: "track_name".into(), 
+                value: track.clone(),
+            },
+            Attribute { 
+                key: CREDENTIAL_LEVEL_KEY.into(), 
+                value: current_level.to_string(),
+            },
+            Attribute { 
+                key: "level_name".into(), 
+                value: level.clone(),
+            },
+            Attribute { 
+                key: "courses_completed".into(), 
+                value: courses_completed.to_string(),
+            },
+        ];
+        record_credential_completion(&mut attribute_list, &course.course_id, completed_at);
+        
         // Create new credential NFT
         // CPI: CreateV2 with plugins
         let create_builder = CreateV2CpiBuilder::new(
@@ -139,30 +212,7 @@ pub fn issue_credential(
                     authority: Some(PluginAuthority::UpdateAuthority),
                 },
                 PluginAuthorityPair {
-                    plugin: Plugin::Attributes(Attributes {
-                        attribute_list: vec![
-                            Attribute { 
-                                key: "track_id".into(), 
-                                value: course.track_id.to_string(),
-                            },
-                            Attribute { 
-                                key: "track_name".into(), 
-                                value: track.clone(),
-                            },
-                            Attribute { 
-                                key: "level".into(), 
-                                value: course.track_level.to_string(),
-                            },
-                            Attribute { 
-                                key: "level_name".into(), 
-                                value: level.to_string(),
-                            },
-                            Attribute { 
-                                key: "courses_completed".into(), 
-                                value: "1".to_string(),
-                            },
-                        ],
-                    }),
+                    plugin: Plugin::Attributes(Attributes { attribute_list }),
                     authority: Some(PluginAuthority::UpdateAuthority),
                 },
             ])
@@ -170,16 +220,17 @@ pub fn issue_credential(
             .map_err(|_| AcademyError::Unauthorized)?;
         
         // Store asset address
-        enrollment.credential_asset = Some(ctx.accounts.credential_asset.key());
+        progress.credential_asset = Some(ctx.accounts.credential_asset.key());
+        enrollment.credential_asset = progress.credential_asset;
         
         // Emit event
-        emit!(CredentialIssued {
+        emit_cpi!(CredentialIssued {
             learner: ctx.accounts.learner.key(),
             track_id: course.track_id,
             credential_asset: ctx.accounts.credential_asset.key(),
             credential_created: true,
             credential_upgraded: false,
-            current_level: course.track_level,
+            current_level,
             timestamp: now,
         });
         
@@ -187,13 +238,34 @@ pub fn issue_credential(
             "Credential created: {} for track {} level {}",
             display_name,
             course.track_id,
-            course.track_level
+            current_level
         );
     } else {
         // Upgrade existing credential
-        if let Some(asset_key) = enrollment.credential_asset {
-            // Count completed courses (simplification - in real app, query all enrollments)
-            let new_courses_count = course.track_level; // Approximation
+        if let Some(asset_key) = progress.credential_asset {
+            // Merge into the existing attributes so untouched keys survive
+            let (_, existing, _) = fetch_asset_plugin::<Attributes>(
+                &ctx.accounts.credential_asset,
+                PluginType::Attributes,
+            )
+            .map_err(|_| AcademyError::InvalidCredentialAsset)?;
+            let mut attribute_list = existing.attribute_list;
+            
+            // Never downgrade: a lower-level course only adds history
+            let current_level = credential_level(&attribute_list).max(current_level);
+            let level = ctx.accounts.track.level_name(current_level);
+            let display_name = format!("{} — {}", track, level);
+            
+            upsert_attribute(&mut attribute_list, "track_id", course.track_id.to_string());
+            upsert_attribute(&mut attribute_list, "track_name", track.clone());
+            upsert_attribute(&mut attribute_list, CREDENTIAL_LEVEL_KEY, current_level.to_string());
+            upsert_attribute(&mut attribute_list, "level_name", level);
+            upsert_attribute(
+                &mut attribute_list,
+                "courses_completed",
+                courses_completed.to_string(),
+            );
+            record_credential_completion(&mut attribute_list, &course.course_id, completed_at);
             
             // Update name and URI
             let update_builder = UpdateV1CpiBuilder::new(
@@ -203,7 +275,9 @@ pub fn issue_credential(
             update_builder
                 .asset(&ctx.accounts.credential_asset)
                 .collection(Some(&ctx.accounts.track_collection))
+                .payer(&ctx.accounts.payer)
                 .authority(Some(&ctx.accounts.config))
+                .system_program(&ctx.accounts.system_program)
                 .new_name(Some(display_name.clone()))
                 .new_uri(Some(metadata_uri))
                 .invoke_signed(config_signer_seeds)
@@ -217,41 +291,30 @@ pub fn issue_credential(
             update_plugin
                 .asset(&ctx.accounts.credential_asset)
                 .collection(Some(&ctx.accounts.track_collection))
+                .payer(&ctx.accounts.payer)
                 .authority(Some(&ctx.accounts.config))
-                .plugin(Plugin::Attributes(Attributes {
-                    attribute_list: vec![
-                        Attribute { 
-                            key: "level".into(), 
-                            value: course.track_level.to_string(),
-                        },
-                        Attribute { 
-                            key: "level_name".into(), 
-                            value: level.to_string(),
-                        },
-                        Attribute { 
-                            key: "courses_completed".into(), 
-                            value: new_courses_count.to_string(),
-                        },
-                    ],
-                }))
+                .system_program(&ctx.accounts.system_program)
+                .plugin(Plugin::Attributes(Attributes { attribute_list }))
                 .invoke_signed(config_signer_seeds)
                 .map_err(|_| AcademyError::Unauthorized)?;
             
+            enrollment.credential_asset = Some(asset_key);
+            
             // Emit event
-            emit!(CredentialIssued {
+            emit_cpi!(CredentialIssued {
                 learner: ctx.accounts.learner.key(),
                 track_id: course.track_id,
                 credential_asset: asset_key,
                 credential_created: false,
                 credential_upgraded: true,
-                current_level: course.track_level,
+                current_level,
                 timestamp: now,
             });
             
             msg!(
                 "Credential upgraded: {} to level {}",
                 display_name,
-                course.track_level
+                current_level
             );
         }
     }
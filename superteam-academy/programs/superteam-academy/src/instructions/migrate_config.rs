@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::AccountMigrated;
+use crate::utils::migrate_account;
+
+/// Upgrade a Config account to the current layout (permissionless).
+/// Academy 0 resolves to the legacy ["config"] address that holds v0 data.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(academy_id: u16)]
+pub struct MigrateConfig<'info> {
+    /// Funds rent for the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA on an older layout
+    /// CHECK: may not deserialize until grown; checked in migrate_account
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [Config::SEED, &Config::academy_seed(academy_id)],
+        bump,
+    )]
+    pub config: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_config(ctx: Context<MigrateConfig>, _academy_id: u16) -> Result<()> {
+    migrate_account::<Config>(
+        &ctx.accounts.config.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Config::SIZE,
+        |config| config.migrate(),
+    )?;
+    
+    emit_cpi!(AccountMigrated {
+        account: ctx.accounts.config.key(),
+        account_type: "Config".to_string(),
+        schema_version: Config::SCHEMA_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Config migrated to v{}: {}", Config::SCHEMA_VERSION, ctx.accounts.config.key());
+    
+    Ok(())
+}
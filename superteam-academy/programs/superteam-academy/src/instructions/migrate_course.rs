@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::AccountMigrated;
+use crate::utils::migrate_account;
+
+/// Upgrade a Course account to the current layout (permissionless).
+/// Academy 0 resolves to the legacy ["course", course_id] address.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String)]
+pub struct MigrateCourse<'info> {
+    /// Funds rent for the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA (migrate it first)
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Course PDA on an older layout
+    /// CHECK: may not deserialize until grown; checked in migrate_account
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course_id.as_bytes()],
+        bump,
+    )]
+    pub course: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_course(ctx: Context<MigrateCourse>, _course_id: String) -> Result<()> {
+    migrate_account::<Course>(
+        &ctx.accounts.course.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Course::SIZE,
+        |course| course.migrate(),
+    )?;
+    
+    emit_cpi!(AccountMigrated {
+        account: ctx.accounts.course.key(),
+        account_type: "Course".to_string(),
+        schema_version: Course::SCHEMA_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Course migrated to v{}: {}", Course::SCHEMA_VERSION, ctx.accounts.course.key());
+    
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::AccountMigrated;
+use crate::utils::migrate_account;
+
+/// Upgrade an Enrollment account to the current layout (permissionless).
+/// Academy 0 resolves to the legacy ["enrollment", course_id, user] address.
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(course_id: String)]
+pub struct MigrateEnrollment<'info> {
+    /// Funds rent for the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA (migrate it first)
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Enrolled learner
+    /// CHECK: only used for the enrollment seeds
+    pub learner: UncheckedAccount<'info>,
+    
+    /// Enrollment PDA on an older layout
+    /// CHECK: may not deserialize until grown; checked in migrate_account
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [
+            b"enrollment".as_ref(),
+            &config.scope_seed(&config.key()),
+            course_id.as_bytes(),
+            learner.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub enrollment: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_enrollment(ctx: Context<MigrateEnrollment>, _course_id: String) -> Result<()> {
+    migrate_account::<Enrollment>(
+        &ctx.accounts.enrollment.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        Enrollment::SIZE,
+        |enrollment| enrollment.migrate(),
+    )?;
+    
+    emit_cpi!(AccountMigrated {
+        account: ctx.accounts.enrollment.key(),
+        account_type: "Enrollment".to_string(),
+        schema_version: Enrollment::SCHEMA_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Enrollment migrated to v{}: {}", Enrollment::SCHEMA_VERSION, ctx.accounts.enrollment.key());
+    
+    Ok(())
+}
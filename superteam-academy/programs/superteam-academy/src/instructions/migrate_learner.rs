@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::events::AccountMigrated;
+use crate::utils::migrate_account;
+
+/// Upgrade a LearnerProfile account to the current layout (permissionless).
+/// Academy 0 resolves to the legacy ["learner", user] address.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MigrateLearner<'info> {
+    /// Funds rent for the larger layout
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA (migrate it first)
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Profile owner
+    /// CHECK: only used for the profile seeds
+    pub learner: UncheckedAccount<'info>,
+    
+    /// LearnerProfile PDA on an older layout
+    /// CHECK: may not deserialize until grown; checked in migrate_account
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
+        bump,
+    )]
+    pub learner_profile: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_learner(ctx: Context<MigrateLearner>) -> Result<()> {
+    migrate_account::<LearnerProfile>(
+        &ctx.accounts.learner_profile.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        LearnerProfile::SIZE,
+        |learner| learner.migrate(),
+    )?;
+    
+    emit_cpi!(AccountMigrated {
+        account: ctx.accounts.learner_profile.key(),
+        account_type: "LearnerProfile".to_string(),
+        schema_version: LearnerProfile::SCHEMA_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("LearnerProfile migrated to v{}: {}", LearnerProfile::SCHEMA_VERSION, ctx.accounts.learner_profile.key());
+    
+    Ok(())
+}
@@ -1,33 +1,105 @@
 pub mod initialize;
+pub mod create_xp_group;
 pub mod create_season;
 pub mod close_season;
+pub mod auto_close_season;
+pub mod commit_season_leaderboard;
 pub mod update_config;
+pub mod execute_config_change;
+pub mod cancel_config_change;
+pub mod init_admin_council;
+pub mod update_admin_council;
+pub mod create_admin_proposal;
+pub mod approve_admin_proposal;
+pub mod execute_admin_proposal;
+pub mod cancel_admin_proposal;
+pub mod init_signer_registry;
+pub mod set_signer;
+pub mod remove_signer;
+pub mod rotate_signer;
+pub mod set_pause_flags;
+pub mod set_course_frozen;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod cancel_authority_transfer;
+pub mod create_multiplier_window;
+pub mod close_multiplier_window;
+pub mod create_track;
+pub mod update_track;
+pub mod create_track_collection;
 pub mod create_course;
 pub mod update_course;
+pub mod init_course_stats;
+pub mod create_course_index_page;
+pub mod index_course;
 pub mod init_learner;
 pub mod register_referral;
 pub mod claim_achievement;
+pub mod claim_season_reward;
+pub mod revoke_xp;
 pub mod award_streak_freeze;
 pub mod enroll;
 pub mod complete_lesson;
 pub mod finalize_course;
 pub mod claim_completion_bonus;
 pub mod issue_credential;
+pub mod revoke_credential;
+pub mod reinstate_credential;
 pub mod close_enrollment;
+pub mod migrate_config;
+pub mod migrate_course;
+pub mod migrate_learner;
+pub mod migrate_enrollment;
 
 pub use initialize::*;
+pub use create_xp_group::*;
 pub use create_season::*;
 pub use close_season::*;
+pub use auto_close_season::*;
+pub use commit_season_leaderboard::*;
 pub use update_config::*;
+pub use execute_config_change::*;
+pub use cancel_config_change::*;
+pub use init_admin_council::*;
+pub use update_admin_council::*;
+pub use create_admin_proposal::*;
+pub use approve_admin_proposal::*;
+pub use execute_admin_proposal::*;
+pub use cancel_admin_proposal::*;
+pub use init_signer_registry::*;
+pub use set_signer::*;
+pub use remove_signer::*;
+pub use rotate_signer::*;
+pub use set_pause_flags::*;
+pub use set_course_frozen::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use cancel_authority_transfer::*;
+pub use create_multiplier_window::*;
+pub use close_multiplier_window::*;
+pub use create_track::*;
+pub use update_track::*;
+pub use create_track_collection::*;
 pub use create_course::*;
 pub use update_course::*;
+pub use init_course_stats::*;
+pub use create_course_index_page::*;
+pub use index_course::*;
 pub use init_learner::*;
 pub use register_referral::*;
 pub use claim_achievement::*;
+pub use claim_season_reward::*;
+pub use revoke_xp::*;
 pub use award_streak_freeze::*;
 pub use enroll::*;
 pub use complete_lesson::*;
 pub use finalize_course::*;
 pub use claim_completion_bonus::*;
 pub use issue_credential::*;
+pub use revoke_credential::*;
+pub use reinstate_credential::*;
 pub use close_enrollment::*;
+pub use migrate_config::*;
+pub use migrate_course::*;
+pub use migrate_learner::*;
+pub use migrate_enrollment::*;
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AuthorityTransferProposed;
+
+/// Propose a new platform authority (step 1 of 2)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Current platform authority
+    pub authority: Signer<'info>,
+}
+
+pub fn propose_authority(
+    ctx: Context<ProposeAuthority>,
+    new_authority: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    // Replaces any earlier proposal
+    config.pending_authority = Some(new_authority);
+    
+    emit_cpi!(AuthorityTransferProposed {
+        authority: config.authority,
+        pending_authority: new_authority,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Authority transfer proposed to: {}", new_authority);
+    
+    Ok(())
+}
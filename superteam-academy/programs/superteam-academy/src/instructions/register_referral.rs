@@ -2,8 +2,10 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::ReferralRegistered;
 
 /// Register a referral
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RegisterReferral<'info> {
     #[account(mut)]
@@ -12,10 +14,17 @@ pub struct RegisterReferral<'info> {
     /// The new learner
     pub learner: Signer<'info>,
     
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
     /// LearnerProfile PDA (referrer)
     #[account(
         mut,
-        seeds = [LearnerProfile::SEED, referrer.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), referrer.key().as_ref()],
         bump = referrer_profile.bump,
     )]
     pub referrer_profile: Account<'info, LearnerProfile>,
@@ -26,7 +35,7 @@ pub struct RegisterReferral<'info> {
     /// LearnerProfile PDA (new learner)
     #[account(
         mut,
-        seeds = [LearnerProfile::SEED, learner.key().as_ref()],
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
         bump = learner_profile.bump,
     )]
     pub learner_profile: Account<'info, LearnerProfile>,
@@ -36,6 +45,8 @@ pub fn register_referral(ctx: Context<RegisterReferral>) -> Result<()> {
     let learner_key = ctx.accounts.learner.key();
     let referrer_key = ctx.accounts.referrer.key();
     
+    ctx.accounts.config.require_not_paused(Config::PAUSE_REFERRALS)?;
+    
     // Cannot refer self
     require!(
         learner_key != referrer_key,
@@ -60,6 +71,12 @@ pub fn register_referral(ctx: Context<RegisterReferral>) -> Result<()> {
         .checked_add(1)
         .ok_or(AcademyError::Overflow)?;
     
+    emit_cpi!(ReferralRegistered {
+        referrer: referrer_key,
+        referee: learner_key,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
     msg!("Referral registered: {} -> {}", learner_key, referrer_key);
     
     Ok(())
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::SignerRemoved;
+
+/// Remove a signer immediately (e.g. leaked key)
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveSigner<'info> {
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// SignerRegistry PDA
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+}
+
+pub fn remove_signer(ctx: Context<RemoveSigner>, signer: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.signer_registry;
+    
+    let index = registry.find(&signer).ok_or(AcademyError::SignerNotFound)?;
+    registry.signers.remove(index);
+    
+    emit_cpi!(SignerRemoved {
+        signer,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Signer removed: {}", signer);
+    
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::CredentialRevoked;
+use crate::utils::write_credential_revocation;
+
+/// Longest revocation reason stored on the credential
+pub const MAX_REVOCATION_REASON_LEN: usize = 64;
+
+/// Invalidate a learner's track credential (e.g. after cheating).
+/// The asset stays in the wallet, flagged by a `revoked` attribute.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeCredential<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Config PDA (credential update authority)
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// Track PDA
+    #[account(
+        seeds = [Track::SEED, config.key().as_ref(), &track.track_id.to_le_bytes()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+    
+    /// Learner wallet
+    /// CHECK: Only used for PDA derivation
+    pub learner: AccountInfo<'info>,
+    
+    /// Learner's progress through the track
+    #[account(
+        mut,
+        seeds = [
+            TrackProgress::SEED,
+            config.key().as_ref(),
+            learner.key().as_ref(),
+            &track.track_id.to_le_bytes(),
+        ],
+        bump = track_progress.bump,
+    )]
+    pub track_progress: Box<Account<'info, TrackProgress>>,
+    
+    /// Track collection NFT
+    /// CHECK: Must be the collection registered on the track
+    #[account(
+        mut,
+        address = track.collection @ AcademyError::InvalidTrackCollection,
+    )]
+    pub track_collection: AccountInfo<'info>,
+    
+    /// Credential asset
+    /// CHECK: Must be the learner's track credential
+    #[account(
+        mut,
+        constraint = track_progress.credential_asset == Some(credential_asset.key())
+            @ AcademyError::InvalidCredentialAsset,
+    )]
+    pub credential_asset: AccountInfo<'info>,
+    
+    /// Metaplex Core program
+    /// CHECK: Address checked
+    #[account(address = mpl_core::ID)]
+    pub mpl_core_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn revoke_credential(ctx: Context<RevokeCredential>, reason: String) -> Result<()> {
+    require!(
+        reason.len() <= MAX_REVOCATION_REASON_LEN,
+        AcademyError::RevocationReasonTooLong
+    );
+    require!(
+        ctx.accounts.track_progress.revoked_at.is_none(),
+        AcademyError::CredentialRevoked
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    write_credential_revocation(
+        &ctx.accounts.config,
+        &ctx.accounts.credential_asset,
+        &ctx.accounts.track_collection,
+        &ctx.accounts.payer,
+        &ctx.accounts.mpl_core_program,
+        &ctx.accounts.system_program,
+        Some((reason.as_str(), now)),
+    )?;
+    ctx.accounts.track_progress.revoked_at = Some(now);
+    
+    emit_cpi!(CredentialRevoked {
+        learner: ctx.accounts.track_progress.learner,
+        track_id: ctx.accounts.track_progress.track_id,
+        credential_asset: ctx.accounts.credential_asset.key(),
+        reason: reason.clone(),
+        timestamp: now,
+    });
+    
+    msg!(
+        "Credential {} revoked: {}",
+        ctx.accounts.credential_asset.key(),
+        reason
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Token2022, TokenAccount, Mint, Burn};
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::XpRevoked;
+
+/// Claw back XP via the permanent delegate (fraud cases)
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(season: u16)]
+pub struct RevokeXp<'info> {
+    /// Platform authority or a signer with the revoke role
+    pub signer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Backend signer registry (not needed when the authority signs)
+    #[account(
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Option<Account<'info, SignerRegistry>>,
+    
+    /// Season archive PDA the XP was minted in
+    #[account(
+        mut,
+        seeds = [Season::SEED, config.key().as_ref(), &season.to_le_bytes()],
+        bump = season_record.bump,
+    )]
+    pub season_record: Account<'info, Season>,
+    
+    /// Learner wallet
+    /// CHECK: Verified by PDA
+    pub learner: AccountInfo<'info>,
+    
+    /// LearnerProfile PDA
+    #[account(
+        mut,
+        seeds = [LearnerProfile::SEED, &config.scope_seed(&config.key()), learner.key().as_ref()],
+        bump = learner_profile.bump,
+        constraint = learner_profile.authority == learner.key() @ AcademyError::Unauthorized,
+    )]
+    pub learner_profile: Account<'info, LearnerProfile>,
+    
+    /// Course PDA (required to clear a lesson)
+    pub course: Option<Account<'info, Course>>,
+    
+    /// Enrollment PDA (required to clear a lesson)
+    #[account(mut)]
+    pub enrollment: Option<Account<'info, Enrollment>>,
+    
+    /// CourseStats PDA of the course (required to clear a lesson)
+    #[account(mut)]
+    pub course_stats: Option<Box<Account<'info, CourseStats>>>,
+    
+    /// Season XP Mint (Token-2022)
+    #[account(
+        mut,
+        address = season_record.mint @ AcademyError::SeasonNotActive,
+    )]
+    pub xp_mint: InterfaceAccount<'info, Mint>,
+    
+    /// Learner's XP token account
+    #[account(
+        mut,
+        token::mint = xp_mint,
+        token::authority = learner,
+    )]
+    pub learner_token: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Config PDA as permanent delegate
+    /// CHECK: Derived from config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config_pda: AccountInfo<'info>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Params for revoke_xp
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct RevokeXpParams {
+    pub amount: u64,
+    pub reason_code: u16,
+    pub lesson_index: Option<u8>,
+    pub achievement_index: Option<u8>,
+}
+
+pub fn revoke_xp(
+    ctx: Context<RevokeXp>,
+    season: u16,
+    params: RevokeXpParams,
+) -> Result<()> {
+    let learner_key = ctx.accounts.learner.key();
+    let now = Clock::get()?.unix_timestamp;
+    let mut course_key = None;
+    
+    if ctx.accounts.signer.key() != ctx.accounts.config.authority {
+        let registry = ctx
+            .accounts
+            .signer_registry
+            .as_ref()
+            .ok_or(AcademyError::Unauthorized)?;
+        registry.check(ctx.accounts.signer.key, SignerRegistry::PERM_REVOKE_XP, now)?;
+    }
+    
+    // Clear offending lesson
+    if let Some(lesson_index) = params.lesson_index {
+        let course = ctx.accounts.course.as_ref().ok_or(AcademyError::NotEnrolled)?;
+        let enrollment = ctx.accounts.enrollment.as_mut().ok_or(AcademyError::NotEnrolled)?;
+        
+        // Enrollment must be this learner's, for this course
+        require!(
+            enrollment.course == course.key(),
+            AcademyError::EnrollmentCourseMismatch
+        );
+        let expected = Pubkey::create_program_address(
+            &[
+                b"enrollment",
+                &ctx.accounts.config.scope_seed(&ctx.accounts.config.key()),
+                course.course_id.as_bytes(),
+                learner_key.as_ref(),
+                &[enrollment.bump],
+            ],
+            ctx.program_id,
+        )
+        .map_err(|_| AcademyError::EnrollmentCourseMismatch)?;
+        require!(
+            expected == enrollment.key(),
+            AcademyError::EnrollmentCourseMismatch
+        );
+        
+        require!(
+            enrollment.clear_lesson(lesson_index),
+            AcademyError::LessonNotCompleted
+        );
+        
+        // Lesson funnel follows the cleared bit
+        let course_stats = ctx
+            .accounts
+            .course_stats
+            .as_mut()
+            .ok_or(AcademyError::NotEnrolled)?;
+        require_keys_eq!(
+            course_stats.course,
+            course.key(),
+            AcademyError::EnrollmentCourseMismatch
+        );
+        course_stats.unrecord_lesson(lesson_index)?;
+        
+        course_key = Some(course.key());
+    }
+    
+    // Clear offending achievement
+    let learner_profile = &mut ctx.accounts.learner_profile;
+    if let Some(achievement_index) = params.achievement_index {
+        require!(
+            learner_profile.is_achievement_claimed(achievement_index),
+            AcademyError::AchievementNotClaimed
+        );
+        learner_profile.unclaim_achievement(achievement_index);
+    }
+    
+    // Burn via permanent delegate
+    if params.amount > 0 {
+        let academy_seed = Config::academy_seed(ctx.accounts.config.academy_id);
+        let config_seeds = &[Config::SEED, &academy_seed, &[ctx.accounts.config.bump]];
+        let signer_seeds = &[&config_seeds[..]];
+        
+        let cpi_accounts = Burn {
+            mint: ctx.accounts.xp_mint.to_account_info(),
+            from: ctx.accounts.learner_token.to_account_info(),
+            authority: ctx.accounts.config_pda.clone(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        anchor_spl::token_interface::burn(cpi_ctx, params.amount)?;
+        
+        ctx.accounts.season_record.record_revoked(params.amount)?;
+        
+        // Lifetime/season XP and level follow the burn
+        learner_profile.debit_xp(params.amount, season);
+        learner_profile.level = ctx.accounts.config.level_for_xp(learner_profile.lifetime_xp);
+    }
+    
+    emit_cpi!(XpRevoked {
+        learner: learner_key,
+        season,
+        amount: params.amount,
+        reason_code: params.reason_code,
+        course: course_key,
+        lesson_index: params.lesson_index,
+        achievement_index: params.achievement_index,
+        revoked_by: ctx.accounts.signer.key(),
+        timestamp: now,
+    });
+    
+    msg!(
+        "XP revoked: {} from {} (season {}, reason {})",
+        params.amount,
+        learner_key,
+        season,
+        params.reason_code
+    );
+    
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::{ConfigChangeQueued, SignerRotationStarted};
+
+/// Rotate a signer key; old and new keys overlap until the deadline.
+/// With a config timelock set, the rotation is queued instead of started.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RotateSigner<'info> {
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// SignerRegistry PDA
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    /// Pays rent for queued changes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Queued change (required when a timelock is set)
+    #[account(
+        init,
+        payer = payer,
+        space = PendingConfigChange::SIZE,
+        seeds = [
+            PendingConfigChange::SEED,
+            config.key().as_ref(),
+            &config.next_config_change_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_change: Option<Account<'info, PendingConfigChange>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn rotate_signer(
+    ctx: Context<RotateSigner>,
+    old_signer: Pubkey,
+    next_signer: Pubkey,
+    rotation_deadline: i64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    
+    if config.config_timelock > 0 {
+        let pending = ctx
+            .accounts
+            .pending_change
+            .as_mut()
+            .ok_or(AcademyError::TimelockRequired)?;
+        let execute_after = pending.queue(
+            config,
+            ConfigChange::RotateSigner {
+                old_signer,
+                next_signer,
+                rotation_deadline,
+            },
+            ctx.accounts.authority.key(),
+            ctx.accounts.payer.key(),
+            ctx.bumps.pending_change.ok_or(AcademyError::TimelockRequired)?,
+            now,
+        )?;
+        // The overlap must still be open once the change can execute
+        require!(
+            rotation_deadline > execute_after,
+            AcademyError::InvalidRotationDeadline
+        );
+        
+        emit_cpi!(ConfigChangeQueued {
+            change_id: pending.change_id,
+            proposed_by: pending.proposed_by,
+            execute_after,
+            timestamp: now,
+        });
+        
+        msg!("Signer rotation {} queued until {}", pending.change_id, execute_after);
+        
+        return Ok(());
+    }
+    
+    require!(
+        ctx.accounts.pending_change.is_none(),
+        AcademyError::TimelockNotEnabled
+    );
+    ctx.accounts
+        .signer_registry
+        .rotate(&old_signer, next_signer, rotation_deadline, now)?;
+    
+    emit_cpi!(SignerRotationStarted {
+        old_signer,
+        next_signer,
+        rotation_deadline,
+        timestamp: now,
+    });
+    
+    msg!("Signer rotation: {} -> {}", old_signer, next_signer);
+    msg!("Old signer accepted until: {}", rotation_deadline);
+    
+    Ok(())
+}
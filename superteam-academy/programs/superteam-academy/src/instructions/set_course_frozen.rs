@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::CourseFreezeUpdated;
+
+/// Freeze or unfreeze a single course
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetCourseFrozen<'info> {
+    /// Platform authority or pauser
+    #[account(
+        constraint = config.is_pauser(signer.key) @ AcademyError::Unauthorized,
+    )]
+    pub signer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Course PDA
+    #[account(
+        mut,
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
+        bump = course.bump,
+    )]
+    pub course: Account<'info, Course>,
+}
+
+pub fn set_course_frozen(ctx: Context<SetCourseFrozen>, frozen: bool) -> Result<()> {
+    let course = &mut ctx.accounts.course;
+    course.is_frozen = frozen;
+    
+    emit_cpi!(CourseFreezeUpdated {
+        course: course.key(),
+        frozen,
+        updated_by: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Course {} frozen: {}", course.course_id, frozen);
+    
+    Ok(())
+}
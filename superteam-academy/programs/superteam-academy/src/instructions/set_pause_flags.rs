@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::PauseFlagsUpdated;
+
+/// Set emergency pause flags
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPauseFlags<'info> {
+    /// Platform authority or pauser
+    #[account(
+        constraint = config.is_pauser(signer.key) @ AcademyError::Unauthorized,
+    )]
+    pub signer: Signer<'info>,
+    
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+}
+
+pub fn set_pause_flags(ctx: Context<SetPauseFlags>, paused: u8) -> Result<()> {
+    require!(
+        paused & !Config::PAUSE_ALL == 0,
+        AcademyError::InvalidPauseFlags
+    );
+    
+    let config = &mut ctx.accounts.config;
+    config.paused = paused;
+    
+    emit_cpi!(PauseFlagsUpdated {
+        paused,
+        updated_by: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Pause flags set to: {:#04x}", paused);
+    
+    Ok(())
+}
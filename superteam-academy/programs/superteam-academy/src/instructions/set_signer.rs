@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::{ConfigChangeQueued, SignerUpdated};
+
+/// Add a signer or change its permissions / quota.
+/// With a config timelock set, the change is queued instead of applied.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSigner<'info> {
+    /// Config PDA
+    #[account(
+        mut,
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// SignerRegistry PDA
+    #[account(
+        mut,
+        seeds = [SignerRegistry::SEED, config.key().as_ref()],
+        bump = signer_registry.bump,
+    )]
+    pub signer_registry: Account<'info, SignerRegistry>,
+    
+    /// Pays rent for queued changes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Queued change (required when a timelock is set)
+    #[account(
+        init,
+        payer = payer,
+        space = PendingConfigChange::SIZE,
+        seeds = [
+            PendingConfigChange::SEED,
+            config.key().as_ref(),
+            &config.next_config_change_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_change: Option<Account<'info, PendingConfigChange>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Params for set_signer
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SetSignerParams {
+    pub signer: Pubkey,
+    pub permissions: u8,
+    pub daily_xp_quota: u32,
+    pub expires_at: Option<i64>,
+}
+
+pub fn set_signer(ctx: Context<SetSigner>, params: SetSignerParams) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    
+    if config.config_timelock > 0 {
+        require!(
+            params.permissions & !SignerRegistry::PERM_ALL == 0,
+            AcademyError::InvalidPermissions
+        );
+        let pending = ctx
+            .accounts
+            .pending_change
+            .as_mut()
+            .ok_or(AcademyError::TimelockRequired)?;
+        let execute_after = pending.queue(
+            config,
+            ConfigChange::SetSigner(params),
+            ctx.accounts.authority.key(),
+            ctx.accounts.payer.key(),
+            ctx.bumps.pending_change.ok_or(AcademyError::TimelockRequired)?,
+            now,
+        )?;
+        
+        emit_cpi!(ConfigChangeQueued {
+            change_id: pending.change_id,
+            proposed_by: pending.proposed_by,
+            execute_after,
+            timestamp: now,
+        });
+        
+        msg!("Signer change {} queued until {}", pending.change_id, execute_after);
+        
+        return Ok(());
+    }
+    
+    require!(
+        ctx.accounts.pending_change.is_none(),
+        AcademyError::TimelockNotEnabled
+    );
+    ctx.accounts.signer_registry.set_signer(&params, now)?;
+    
+    emit_cpi!(SignerUpdated {
+        signer: params.signer,
+        permissions: params.permissions,
+        daily_xp_quota: params.daily_xp_quota,
+        expires_at: params.expires_at,
+        timestamp: now,
+    });
+    
+    msg!("Signer {} permissions: {:#04x}", params.signer, params.permissions);
+    
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::AdminCouncilUpdated;
+
+/// Change council members and threshold.
+/// Only the council itself may sign, i.e. through execute_admin_proposal.
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateAdminCouncil<'info> {
+    /// AdminCouncil PDA
+    #[account(
+        mut,
+        seeds = [AdminCouncil::SEED, admin_council.config.as_ref()],
+        bump = admin_council.bump,
+    )]
+    pub admin_council: Account<'info, AdminCouncil>,
+    
+    /// Council PDA signing for an approved proposal
+    #[account(address = admin_council.key() @ AcademyError::Unauthorized)]
+    pub authority: Signer<'info>,
+}
+
+pub fn update_admin_council(
+    ctx: Context<UpdateAdminCouncil>,
+    members: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let council = &mut ctx.accounts.admin_council;
+    council.update(members, threshold)?;
+    
+    emit_cpi!(AdminCouncilUpdated {
+        council: council.key(),
+        members: council.members.clone(),
+        threshold,
+        members_epoch: council.members_epoch,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+    
+    msg!("Admin council updated: {} of {}", threshold, council.members.len());
+    
+    Ok(())
+}
@@ -2,29 +2,111 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::{ConfigChangeQueued, ConfigUpdated};
 
-/// Update config: rotate backend signer, adjust rate limits
+/// Update config: adjust rate limits, level curve, pauser and timelock.
+/// Backend signers are managed in the SignerRegistry.
+/// With a timelock set, the change is queued instead of applied.
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateConfig<'info> {
     /// Config PDA
     #[account(
         mut,
-        seeds = [Config::SEED],
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
         bump = config.bump,
-        has_one = authority @ AcademyError::Unauthorized,
     )]
     pub config: Account<'info, Config>,
     
-    /// Platform authority
+    /// Platform authority or admin council
+    #[account(
+        constraint = config.is_admin(authority.key) @ AcademyError::Unauthorized,
+    )]
     pub authority: Signer<'info>,
+    
+    /// Pays rent for queued changes
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// Queued change (required when a timelock is set)
+    #[account(
+        init,
+        payer = payer,
+        space = PendingConfigChange::SIZE,
+        seeds = [
+            PendingConfigChange::SEED,
+            config.key().as_ref(),
+            &config.next_config_change_id.to_le_bytes(),
+        ],
+        bump,
+    )]
+    pub pending_change: Option<Account<'info, PendingConfigChange>>,
+    
+    pub system_program: Program<'info, System>,
 }
 
 /// Params for update_config
 #[derive(Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateConfigParams {
-    pub backend_signer: Option<Pubkey>,
     pub max_daily_xp: Option<u32>,
     pub max_achievement_xp: Option<u32>,
+    pub level_base_xp: Option<u32>,
+    pub level_growth_bps: Option<u16>,
+    pub pauser: Option<Pubkey>,
+    pub config_timelock: Option<i64>,
+}
+
+impl UpdateConfigParams {
+    pub const SIZE: usize = 5 + // max_daily_xp
+        5 +  // max_achievement_xp
+        5 +  // level_base_xp
+        3 +  // level_growth_bps
+        33 + // pauser
+        9;   // config_timelock
+    
+    /// Write the set fields into `config`; returns the updated field names
+    pub fn apply(&self, config: &mut Config) -> Result<Vec<&'static str>> {
+        let mut updated = Vec::new();
+        
+        if let Some(max_daily_xp) = self.max_daily_xp {
+            config.max_daily_xp = max_daily_xp;
+            updated.push("max_daily_xp");
+            msg!("Max daily XP updated to: {}", max_daily_xp);
+        }
+        
+        if let Some(max_achievement_xp) = self.max_achievement_xp {
+            config.max_achievement_xp = max_achievement_xp;
+            updated.push("max_achievement_xp");
+            msg!("Max achievement XP updated to: {}", max_achievement_xp);
+        }
+        
+        if let Some(level_base_xp) = self.level_base_xp {
+            config.level_base_xp = level_base_xp;
+            updated.push("level_base_xp");
+            msg!("Level base XP updated to: {}", level_base_xp);
+        }
+        
+        if let Some(level_growth_bps) = self.level_growth_bps {
+            config.level_growth_bps = level_growth_bps;
+            updated.push("level_growth_bps");
+            msg!("Level growth updated to: {} bps", level_growth_bps);
+        }
+        
+        if let Some(pauser) = self.pauser {
+            config.pauser = pauser;
+            updated.push("pauser");
+            msg!("Pauser updated to: {}", pauser);
+        }
+        
+        if let Some(config_timelock) = self.config_timelock {
+            require!(config_timelock >= 0, AcademyError::InvalidTimelock);
+            config.config_timelock = config_timelock;
+            updated.push("config_timelock");
+            msg!("Config timelock updated to: {}s", config_timelock);
+        }
+        
+        Ok(updated)
+    }
 }
 
 pub fn update_config(
@@ -32,21 +114,45 @@ pub fn update_config(
     params: UpdateConfigParams,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
     
-    if let Some(backend_signer) = params.backend_signer {
-        config.backend_signer = backend_signer;
-        msg!("Backend signer updated to: {}", backend_signer);
+    if config.config_timelock == 0 {
+        require!(
+            ctx.accounts.pending_change.is_none(),
+            AcademyError::TimelockNotEnabled
+        );
+        for field in params.apply(config)? {
+            emit_cpi!(ConfigUpdated {
+                field: field.to_string(),
+                timestamp: now,
+            });
+        }
+        return Ok(());
     }
     
-    if let Some(max_daily_xp) = params.max_daily_xp {
-        config.max_daily_xp = max_daily_xp;
-        msg!("Max daily XP updated to: {}", max_daily_xp);
-    }
+    // Timelocked: queue the change for execute_config_change
+    let pending = ctx
+        .accounts
+        .pending_change
+        .as_mut()
+        .ok_or(AcademyError::TimelockRequired)?;
+    let execute_after = pending.queue(
+        config,
+        ConfigChange::Config(params),
+        ctx.accounts.authority.key(),
+        ctx.accounts.payer.key(),
+        ctx.bumps.pending_change.ok_or(AcademyError::TimelockRequired)?,
+        now,
+    )?;
     
-    if let Some(max_achievement_xp) = params.max_achievement_xp {
-        config.max_achievement_xp = max_achievement_xp;
-        msg!("Max achievement XP updated to: {}", max_achievement_xp);
-    }
+    emit_cpi!(ConfigChangeQueued {
+        change_id: pending.change_id,
+        proposed_by: pending.proposed_by,
+        execute_after,
+        timestamp: now,
+    });
+    
+    msg!("Config change {} queued until {}", pending.change_id, execute_after);
     
     Ok(())
 }
@@ -2,14 +2,23 @@ use anchor_lang::prelude::*;
 
 use crate::state::*;
 use crate::error::AcademyError;
+use crate::events::CourseUpdated;
 
 /// Update course content, rewards, or deactivate
+#[event_cpi]
 #[derive(Accounts)]
 pub struct UpdateCourse<'info> {
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+    )]
+    pub config: Account<'info, Config>,
+    
     /// Course PDA
     #[account(
         mut,
-        seeds = Course::seeds(&course.course_id),
+        seeds = [Course::SEED, &config.scope_seed(&config.key()), course.course_id.as_bytes()],
         bump = course.bump,
         has_one = authority @ AcademyError::Unauthorized,
     )]
@@ -17,6 +26,32 @@ pub struct UpdateCourse<'info> {
     
     /// Course authority
     pub authority: Signer<'info>,
+    
+    /// Global CourseIndex page listing this course (required to toggle is_active)
+    #[account(
+        mut,
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &CourseIndex::GLOBAL.to_le_bytes(),
+            &course.global_index_page.to_le_bytes(),
+        ],
+        bump = global_index.bump,
+    )]
+    pub global_index: Option<Box<Account<'info, CourseIndex>>>,
+    
+    /// Track CourseIndex page listing this course (required to toggle is_active)
+    #[account(
+        mut,
+        seeds = [
+            CourseIndex::SEED,
+            config.key().as_ref(),
+            &course.track_id.to_le_bytes(),
+            &course.track_index_page.to_le_bytes(),
+        ],
+        bump = track_index.bump,
+    )]
+    pub track_index: Option<Box<Account<'info, CourseIndex>>>,
 }
 
 /// Params for update_course
@@ -44,6 +79,21 @@ pub fn update_course(
     
     if let Some(is_active) = params.is_active {
         course.is_active = is_active;
+        
+        // Keep the catalogue in sync
+        if course.is_indexed {
+            let course_key = course.key();
+            ctx.accounts
+                .global_index
+                .as_mut()
+                .ok_or(AcademyError::CourseIndexRequired)?
+                .set_active(&course_key, is_active)?;
+            ctx.accounts
+                .track_index
+                .as_mut()
+                .ok_or(AcademyError::CourseIndexRequired)?
+                .set_active(&course_key, is_active)?;
+        }
     }
     
     if let Some(completion_bonus_xp) = params.completion_bonus_xp {
@@ -60,6 +110,12 @@ pub fn update_course(
     
     course.updated_at = now;
     
+    emit_cpi!(CourseUpdated {
+        course: course.key(),
+        version: course.version,
+        timestamp: now,
+    });
+    
     msg!("Course updated: {}", course.course_id);
     msg!("Version: {}", course.version);
     
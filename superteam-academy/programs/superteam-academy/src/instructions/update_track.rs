@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+
+use crate::state::*;
+use crate::error::AcademyError;
+use crate::events::TrackUpdated;
+
+/// Rename, re-describe, extend, relabel or deactivate a track
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UpdateTrack<'info> {
+    /// Config PDA
+    #[account(
+        seeds = [Config::SEED, &Config::academy_seed(config.academy_id)],
+        bump = config.bump,
+        has_one = authority @ AcademyError::Unauthorized,
+    )]
+    pub config: Account<'info, Config>,
+    
+    /// Platform authority
+    pub authority: Signer<'info>,
+    
+    /// Track PDA
+    #[account(
+        mut,
+        seeds = [Track::SEED, config.key().as_ref(), &track.track_id.to_le_bytes()],
+        bump = track.bump,
+    )]
+    pub track: Account<'info, Track>,
+}
+
+/// Params for update_track
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateTrackParams {
+    pub name: Option<String>,
+    pub description_uri: Option<String>,
+    pub max_level: Option<u8>,
+    pub level_names: Option<Vec<String>>,
+    pub is_active: Option<bool>,
+}
+
+pub fn update_track(ctx: Context<UpdateTrack>, params: UpdateTrackParams) -> Result<()> {
+    let track = &mut ctx.accounts.track;
+    let now = Clock::get()?.unix_timestamp;
+    
+    if let Some(name) = params.name {
+        track.name = name;
+    }
+    
+    if let Some(description_uri) = params.description_uri {
+        track.description_uri = description_uri;
+    }
+    
+    if let Some(max_level) = params.max_level {
+        track.max_level = max_level;
+    }
+    
+    if let Some(level_names) = params.level_names {
+        track.level_names = level_names;
+    }
+    
+    if let Some(is_active) = params.is_active {
+        track.is_active = is_active;
+    }
+    
+    Track::validate(
+        &track.name,
+        &track.description_uri,
+        track.max_level,
+        &track.level_names,
+    )?;
+    track.updated_at = now;
+    
+    emit_cpi!(TrackUpdated {
+        track_id: track.track_id,
+        is_active: track.is_active,
+        timestamp: now,
+    });
+    
+    msg!("Track {} updated: {}", track.track_id, track.name);
+    
+    Ok(())
+}
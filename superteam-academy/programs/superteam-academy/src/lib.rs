@@ -25,7 +25,7 @@ pub mod superteam_academy {
     // PLATFORM MANAGEMENT
     // ═══════════════════════════════════════════════════════════════
     
-    /// Initialize platform
+    /// Initialize an academy
     pub fn initialize(
         ctx: Context<Initialize>,
         params: InitializeParams,
@@ -33,20 +33,44 @@ pub mod superteam_academy {
         instructions::initialize(ctx, params)
     }
     
+    /// Create the XP token group every season mint joins
+    pub fn create_xp_group(ctx: Context<CreateXpGroup>, metadata_uri: String) -> Result<()> {
+        instructions::create_xp_group(ctx, metadata_uri)
+    }
+    
     /// Create new season
     pub fn create_season(
         ctx: Context<CreateSeason>,
         season: u16,
+        ends_at: Option<i64>,
+        metadata_uri: String,
     ) -> Result<()> {
-        instructions::create_season(ctx, season)
+        instructions::create_season(ctx, season, ends_at, metadata_uri)
     }
     
     /// Close current season
-    pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
-        instructions::close_season(ctx)
+    pub fn close_season(
+        ctx: Context<CloseSeason>,
+        leaderboard_root: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::close_season(ctx, leaderboard_root)
+    }
+    
+    /// Close current season after its scheduled end (permissionless)
+    pub fn auto_close_season(ctx: Context<AutoCloseSeason>) -> Result<()> {
+        instructions::auto_close_season(ctx)
     }
     
-    /// Update config
+    /// Commit leaderboard for a closed season
+    pub fn commit_season_leaderboard(
+        ctx: Context<CommitSeasonLeaderboard>,
+        season: u16,
+        leaderboard_root: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_season_leaderboard(ctx, season, leaderboard_root)
+    }
+    
+    /// Update config (queued when a timelock is set)
     pub fn update_config(
         ctx: Context<UpdateConfig>,
         params: UpdateConfigParams,
@@ -54,10 +78,153 @@ pub mod superteam_academy {
         instructions::update_config(ctx, params)
     }
     
+    /// Apply a timelocked config change (permissionless)
+    pub fn execute_config_change(ctx: Context<ExecuteConfigChange>) -> Result<()> {
+        instructions::execute_config_change(ctx)
+    }
+    
+    /// Cancel a timelocked config change
+    pub fn cancel_config_change(ctx: Context<CancelConfigChange>) -> Result<()> {
+        instructions::cancel_config_change(ctx)
+    }
+    
+    /// Create the M-of-N admin council
+    pub fn init_admin_council(
+        ctx: Context<InitAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::init_admin_council(ctx, members, threshold)
+    }
+    
+    /// Change council members / threshold (via an executed proposal)
+    pub fn update_admin_council(
+        ctx: Context<UpdateAdminCouncil>,
+        members: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        instructions::update_admin_council(ctx, members, threshold)
+    }
+    
+    /// Propose an admin action
+    pub fn create_admin_proposal(
+        ctx: Context<CreateAdminProposal>,
+        action: AdminAction,
+        expires_at: i64,
+    ) -> Result<()> {
+        instructions::create_admin_proposal(ctx, action, expires_at)
+    }
+    
+    /// Approve an admin proposal
+    pub fn approve_admin_proposal(ctx: Context<ApproveAdminProposal>) -> Result<()> {
+        instructions::approve_admin_proposal(ctx)
+    }
+    
+    /// Execute an admin proposal once the threshold is met
+    pub fn execute_admin_proposal<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteAdminProposal<'info>>,
+    ) -> Result<()> {
+        instructions::execute_admin_proposal(ctx)
+    }
+    
+    /// Cancel an admin proposal
+    pub fn cancel_admin_proposal(ctx: Context<CancelAdminProposal>) -> Result<()> {
+        instructions::cancel_admin_proposal(ctx)
+    }
+    
+    /// Create the role-scoped signer registry
+    pub fn init_signer_registry(ctx: Context<InitSignerRegistry>) -> Result<()> {
+        instructions::init_signer_registry(ctx)
+    }
+    
+    /// Add a signer or update its permissions and quota
+    pub fn set_signer(ctx: Context<SetSigner>, params: SetSignerParams) -> Result<()> {
+        instructions::set_signer(ctx, params)
+    }
+    
+    /// Remove a signer immediately
+    pub fn remove_signer(ctx: Context<RemoveSigner>, signer: Pubkey) -> Result<()> {
+        instructions::remove_signer(ctx, signer)
+    }
+    
+    /// Rotate a signer key with an overlap window
+    pub fn rotate_signer(
+        ctx: Context<RotateSigner>,
+        old_signer: Pubkey,
+        next_signer: Pubkey,
+        rotation_deadline: i64,
+    ) -> Result<()> {
+        instructions::rotate_signer(ctx, old_signer, next_signer, rotation_deadline)
+    }
+    
+    /// Set emergency pause flags
+    pub fn set_pause_flags(ctx: Context<SetPauseFlags>, paused: u8) -> Result<()> {
+        instructions::set_pause_flags(ctx, paused)
+    }
+    
+    /// Freeze or unfreeze a course
+    pub fn set_course_frozen(ctx: Context<SetCourseFrozen>, frozen: bool) -> Result<()> {
+        instructions::set_course_frozen(ctx, frozen)
+    }
+    
+    /// Propose a new platform authority
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        instructions::propose_authority(ctx, new_authority)
+    }
+    
+    /// Accept a pending authority transfer
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+    
+    /// Cancel a pending authority transfer
+    pub fn cancel_authority_transfer(ctx: Context<CancelAuthorityTransfer>) -> Result<()> {
+        instructions::cancel_authority_transfer(ctx)
+    }
+    
+    /// Schedule XP multiplier window
+    pub fn create_multiplier_window(
+        ctx: Context<CreateMultiplierWindow>,
+        window_id: u32,
+        params: CreateMultiplierWindowParams,
+    ) -> Result<()> {
+        instructions::create_multiplier_window(ctx, window_id, params)
+    }
+    
+    /// Close XP multiplier window
+    pub fn close_multiplier_window(ctx: Context<CloseMultiplierWindow>) -> Result<()> {
+        instructions::close_multiplier_window(ctx)
+    }
+    
     // ═══════════════════════════════════════════════════════════════
     // COURSES
     // ═══════════════════════════════════════════════════════════════
     
+    /// Register a learning track
+    pub fn create_track(
+        ctx: Context<CreateTrack>,
+        track_id: u16,
+        params: CreateTrackParams,
+    ) -> Result<()> {
+        instructions::create_track(ctx, track_id, params)
+    }
+    
+    /// Update a learning track
+    pub fn update_track(ctx: Context<UpdateTrack>, params: UpdateTrackParams) -> Result<()> {
+        instructions::update_track(ctx, params)
+    }
+    
+    /// Create a track's credential collection
+    pub fn create_track_collection(
+        ctx: Context<CreateTrackCollection>,
+        metadata_uri: String,
+    ) -> Result<()> {
+        instructions::create_track_collection(ctx, metadata_uri)
+    }
+    
     /// Create course
     pub fn create_course(
         ctx: Context<CreateCourse>,
@@ -75,6 +242,25 @@ pub mod superteam_academy {
         instructions::update_course(ctx, params)
     }
     
+    /// Create the lesson funnel for a course created before CourseStats
+    pub fn init_course_stats(ctx: Context<InitCourseStats>) -> Result<()> {
+        instructions::init_course_stats(ctx)
+    }
+    
+    /// Open the next page of a CourseIndex
+    pub fn create_course_index_page(
+        ctx: Context<CreateCourseIndexPage>,
+        scope: u16,
+        page: u16,
+    ) -> Result<()> {
+        instructions::create_course_index_page(ctx, scope, page)
+    }
+    
+    /// List a course created before CourseIndex
+    pub fn index_course(ctx: Context<IndexCourse>) -> Result<()> {
+        instructions::index_course(ctx)
+    }
+    
     // ═══════════════════════════════════════════════════════════════
     // LEARNERS
     // ═══════════════════════════════════════════════════════════════
@@ -98,6 +284,27 @@ pub mod superteam_academy {
         instructions::claim_achievement(ctx, achievement_index, xp_reward)
     }
     
+    /// Claim season leaderboard prize
+    pub fn claim_season_reward(
+        ctx: Context<ClaimSeasonReward>,
+        season: u16,
+        rank: u16,
+        season_xp: u64,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_season_reward(ctx, season, rank, season_xp, amount, proof)
+    }
+    
+    /// Claw back XP (fraud)
+    pub fn revoke_xp(
+        ctx: Context<RevokeXp>,
+        season: u16,
+        params: RevokeXpParams,
+    ) -> Result<()> {
+        instructions::revoke_xp(ctx, season, params)
+    }
+    
     /// Award streak freeze
     pub fn award_streak_freeze(ctx: Context<AwardStreakFreeze>) -> Result<()> {
         instructions::award_streak_freeze(ctx)
@@ -143,8 +350,42 @@ pub mod superteam_academy {
         instructions::issue_credential(ctx, metadata_uri)
     }
     
+    /// Revoke a learner's track credential
+    pub fn revoke_credential(ctx: Context<RevokeCredential>, reason: String) -> Result<()> {
+        instructions::revoke_credential(ctx, reason)
+    }
+    
+    /// Reinstate a revoked track credential
+    pub fn reinstate_credential(ctx: Context<ReinstateCredential>) -> Result<()> {
+        instructions::reinstate_credential(ctx)
+    }
+    
     /// Close enrollment
     pub fn close_enrollment(ctx: Context<CloseEnrollment>) -> Result<()> {
         instructions::close_enrollment(ctx)
     }
+    
+    // ═══════════════════════════════════════════════════════════════
+    // MIGRATIONS
+    // ═══════════════════════════════════════════════════════════════
+    
+    /// Upgrade Config to the current layout
+    pub fn migrate_config(ctx: Context<MigrateConfig>, academy_id: u16) -> Result<()> {
+        instructions::migrate_config(ctx, academy_id)
+    }
+    
+    /// Upgrade a Course to the current layout
+    pub fn migrate_course(ctx: Context<MigrateCourse>, course_id: String) -> Result<()> {
+        instructions::migrate_course(ctx, course_id)
+    }
+    
+    /// Upgrade a LearnerProfile to the current layout
+    pub fn migrate_learner(ctx: Context<MigrateLearner>) -> Result<()> {
+        instructions::migrate_learner(ctx)
+    }
+    
+    /// Upgrade an Enrollment to the current layout
+    pub fn migrate_enrollment(ctx: Context<MigrateEnrollment>, course_id: String) -> Result<()> {
+        instructions::migrate_enrollment(ctx, course_id)
+    }
 }
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+
+/// AdminCouncil PDA - Native M-of-N admin set
+/// Seeds: ["admin_council", config]
+#[account]
+pub struct AdminCouncil {
+    /// Config PDA this council administers
+    pub config: Pubkey,
+    
+    /// Council members (approval bit = index)
+    pub members: Vec<Pubkey>,
+    
+    /// Approvals needed to execute a proposal
+    pub threshold: u8,
+    
+    /// Id of the next AdminProposal
+    pub next_proposal_id: u64,
+    
+    /// Bumped on every membership change; voids older proposals
+    pub members_epoch: u32,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminCouncil {
+    /// Approvals are a u16 bitmap
+    pub const MAX_MEMBERS: usize = 16;
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // config
+        4 + Self::MAX_MEMBERS * 32 + // members
+        1 +  // threshold
+        8 +  // next_proposal_id
+        4 +  // members_epoch
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"admin_council";
+    
+    /// Position of `member` in the council
+    pub fn member_index(&self, member: &Pubkey) -> Option<usize> {
+        self.members.iter().position(|m| m == member)
+    }
+    
+    /// Members and threshold form a usable council
+    pub fn is_valid(members: &[Pubkey], threshold: u8) -> bool {
+        let unique = members
+            .iter()
+            .enumerate()
+            .all(|(i, m)| !members[..i].contains(m));
+        unique
+            && members.len() <= Self::MAX_MEMBERS
+            && threshold > 0
+            && threshold as usize <= members.len()
+    }
+    
+    /// Replace members and threshold; approvals of earlier proposals lapse
+    pub fn update(&mut self, members: Vec<Pubkey>, threshold: u8) -> Result<()> {
+        require!(
+            Self::is_valid(&members, threshold),
+            AcademyError::InvalidCouncil
+        );
+        
+        self.members = members;
+        self.threshold = threshold;
+        self.members_epoch = self
+            .members_epoch
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        
+        Ok(())
+    }
+}
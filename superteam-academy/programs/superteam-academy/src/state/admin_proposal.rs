@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
+
+use crate::instructions::{CreateCourseParams, UpdateConfigParams};
+
+/// Admin operation a council proposal executes
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub enum AdminAction {
+    CreateCourse {
+        course_id: String,
+        params: CreateCourseParams,
+    },
+    CreateSeason {
+        season: u16,
+        ends_at: Option<i64>,
+        metadata_uri: String,
+    },
+    CloseSeason {
+        leaderboard_root: Option<[u8; 32]>,
+    },
+    UpdateConfig {
+        params: UpdateConfigParams,
+    },
+    UpdateCouncil {
+        members: Vec<Pubkey>,
+        threshold: u8,
+    },
+}
+
+impl AdminAction {
+    /// Instruction data of the target academy instruction
+    pub fn data(&self) -> Vec<u8> {
+        match self.clone() {
+            AdminAction::CreateCourse { course_id, params } => {
+                crate::instruction::CreateCourse { course_id, params }.data()
+            }
+            AdminAction::CreateSeason { season, ends_at, metadata_uri } => {
+                crate::instruction::CreateSeason { season, ends_at, metadata_uri }.data()
+            }
+            AdminAction::CloseSeason { leaderboard_root } => {
+                crate::instruction::CloseSeason { leaderboard_root }.data()
+            }
+            AdminAction::UpdateConfig { params } => {
+                crate::instruction::UpdateConfig { params }.data()
+            }
+            AdminAction::UpdateCouncil { members, threshold } => {
+                crate::instruction::UpdateAdminCouncil { members, threshold }.data()
+            }
+        }
+    }
+}
+
+/// AdminProposal PDA - Pending council action
+/// Seeds: ["admin_proposal", admin_council, proposal_id.to_le_bytes()]
+#[account]
+pub struct AdminProposal {
+    /// Sequential proposal id
+    pub proposal_id: u64,
+    
+    /// Member that created the proposal (receives rent back)
+    pub proposer: Pubkey,
+    
+    /// Action to execute
+    pub action: AdminAction,
+    
+    /// Bitmap of approving member indices
+    pub approvals: u16,
+    
+    /// Creation timestamp
+    pub created_at: i64,
+    
+    /// Proposal cannot be approved or executed at or after this time
+    pub expires_at: i64,
+    
+    /// Council members_epoch the approvals refer to
+    pub members_epoch: u32,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    pub const BASE_SIZE: usize = 8 + // discriminator
+        8 +  // proposal_id
+        32 + // proposer
+        2 +  // approvals
+        8 +  // created_at
+        8 +  // expires_at
+        4 +  // members_epoch
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"admin_proposal";
+    
+    /// Account size for a proposal carrying `action`
+    pub fn space(action: &AdminAction) -> usize {
+        Self::BASE_SIZE + action.try_to_vec().map_or(0, |data| data.len())
+    }
+    
+    /// Record approval of the member at `index`; false if already approved
+    pub fn approve(&mut self, index: usize) -> bool {
+        let bit = 1u16 << index;
+        if self.approvals & bit != 0 {
+            return false;
+        }
+        self.approvals |= bit;
+        true
+    }
+    
+    /// Number of approvals collected
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
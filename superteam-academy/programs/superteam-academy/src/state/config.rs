@@ -1,13 +1,15 @@
 use anchor_lang::prelude::*;
 
-/// Config PDA - Singleton platform configuration
-/// Seeds: ["config"]
+use crate::error::AcademyError;
+
+/// Config PDA - Per-academy platform configuration
+/// Seeds: ["config", academy_id.to_le_bytes()] (["config"] for academy 0)
 #[account]
 pub struct Config {
     /// Platform authority (multisig/Squads)
     pub authority: Pubkey,
     
-    /// Rotatable backend signer for completions
+    /// Default backend signer, seeded into the SignerRegistry with all permissions
     pub backend_signer: Pubkey,
     
     /// Current active season number
@@ -28,11 +30,44 @@ pub struct Config {
     /// Max XP from a single achievement
     pub max_achievement_xp: u32,
     
+    /// Lifetime XP needed to reach level 1 (0 = levels disabled)
+    pub level_base_xp: u32,
+    
+    /// Growth of each next level's XP step, in basis points (10000 = flat)
+    pub level_growth_bps: u16,
+    
+    /// Paused categories (Config::PAUSE_*)
+    pub paused: u8,
+    
+    /// Delay before update_config changes apply, in seconds (0 = immediate)
+    pub config_timelock: i64,
+    
+    /// Next PendingConfigChange id
+    pub next_config_change_id: u32,
+    
+    /// Account layout version (see `SCHEMA_VERSION`)
+    pub schema_version: u8,
+    
+    /// Academy this config belongs to (namespaces every other PDA)
+    pub academy_id: u16,
+    
     /// Reserved for future use
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 10],
     
     /// PDA bump
     pub bump: u8,
+    
+    /// Scheduled season end (no XP minting at or after this time)
+    pub season_ends_at: Option<i64>,
+    
+    /// Proposed new authority, set until accepted or cancelled
+    pub pending_authority: Option<Pubkey>,
+    
+    /// Emergency key allowed to toggle pause flags (besides authority)
+    pub pauser: Pubkey,
+    
+    /// AdminCouncil PDA allowed to run council actions (besides authority)
+    pub council: Pubkey,
 }
 
 impl Config {
@@ -45,8 +80,116 @@ impl Config {
         8 +  // season_started_at
         4 +  // max_daily_xp
         4 +  // max_achievement_xp
-        32 + // reserved
-        1;   // bump
+        4 +  // level_base_xp
+        2 +  // level_growth_bps
+        1 +  // paused
+        8 +  // config_timelock
+        4 +  // next_config_change_id
+        1 +  // schema_version
+        2 +  // academy_id
+        10 + // reserved
+        1 +  // bump
+        9 +  // season_ends_at (Option<i64> = 1 + 8)
+        33 + // pending_authority (Option<Pubkey> = 1 + 32)
+        32 + // pauser
+        32;  // council
+    
+    /// Current account layout version
+    pub const SCHEMA_VERSION: u8 = 2;
+    
+    /// Upgrade an older layout in place
+    pub fn migrate(&mut self) -> Result<()> {
+        require!(
+            self.schema_version < Self::SCHEMA_VERSION,
+            AcademyError::AlreadyMigrated
+        );
+        // v0 -> v1: the level curve, pause flags, timelock and academy_id
+        // are carved from `_reserved` and decode as 0; season_ends_at,
+        // pending_authority and pauser are appended after `bump` and decode
+        // as None/default from the grown tail. A zero growth rate would
+        // collapse the level curve, so default it to flat.
+        if self.schema_version < 1 && self.level_growth_bps == 0 {
+            self.level_growth_bps = 10_000;
+        }
+        // v1 -> v2: council appended after `bump`, unset until
+        // init_admin_council
+        self.schema_version = Self::SCHEMA_VERSION;
+        Ok(())
+    }
     
     pub const SEED: &'static [u8] = b"config";
+    
+    /// Config PDA seed after SEED. Empty for academy 0, which keeps the
+    /// pre-multi-academy ["config"] address (an empty seed hashes to nothing).
+    pub fn academy_seed(academy_id: u16) -> Vec<u8> {
+        if academy_id == 0 {
+            Vec::new()
+        } else {
+            academy_id.to_le_bytes().to_vec()
+        }
+    }
+    
+    /// Seed scoping Course / LearnerProfile / Enrollment PDAs to the academy
+    /// at `config`. Empty for academy 0, so its accounts keep their addresses.
+    pub fn scope_seed(&self, config: &Pubkey) -> Vec<u8> {
+        if self.academy_id == 0 {
+            Vec::new()
+        } else {
+            config.to_bytes().to_vec()
+        }
+    }
+    
+    /// Highest reachable level
+    pub const MAX_LEVEL: u16 = 100;
+    
+    pub const PAUSE_ENROLLMENT: u8 = 1 << 0;
+    pub const PAUSE_LESSONS: u8 = 1 << 1;
+    pub const PAUSE_ACHIEVEMENTS: u8 = 1 << 2;
+    pub const PAUSE_BONUSES: u8 = 1 << 3;
+    pub const PAUSE_CREDENTIALS: u8 = 1 << 4;
+    pub const PAUSE_REFERRALS: u8 = 1 << 5;
+    pub const PAUSE_ALL: u8 = (1 << 6) - 1;
+    
+    /// Fail with `Paused` while `category` is paused
+    pub fn require_not_paused(&self, category: u8) -> Result<()> {
+        require!(self.paused & category == 0, AcademyError::Paused);
+        Ok(())
+    }
+    
+    /// Whether `key` may toggle pause flags
+    pub fn is_pauser(&self, key: &Pubkey) -> bool {
+        *key == self.authority || *key == self.pauser
+    }
+    
+    /// Whether `key` may run council actions (create_course, create_season,
+    /// close_season, update_config)
+    pub fn is_admin(&self, key: &Pubkey) -> bool {
+        *key == self.authority || *key == self.council
+    }
+    
+    /// Ensure XP can still be minted for the current season
+    pub fn require_season_active(&self, now: i64) -> Result<()> {
+        require!(!self.season_closed, AcademyError::SeasonClosed);
+        if let Some(ends_at) = self.season_ends_at {
+            require!(now < ends_at, AcademyError::SeasonEnded);
+        }
+        Ok(())
+    }
+    
+    /// Level reached with `xp` lifetime XP on the configured curve
+    pub fn level_for_xp(&self, xp: u64) -> u16 {
+        if self.level_base_xp == 0 {
+            return 0;
+        }
+        let xp = xp as u128;
+        let mut level = 0u16;
+        let mut step = self.level_base_xp as u128;
+        let mut threshold = step;
+        while xp >= threshold && level < Self::MAX_LEVEL {
+            level += 1;
+            step = (step * self.level_growth_bps as u128 / 10_000).max(1);
+            threshold += step;
+        }
+        level
+    }
 }
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::error::AcademyError;
+
 /// Course PDA - Course registry
-/// Seeds: ["course", course_id.as_bytes()]
+/// Seeds: ["course", config, course_id.as_bytes()] (config omitted for academy 0)
 #[account]
 pub struct Course {
     /// Unique course identifier (slug, max 32 chars)
@@ -49,7 +51,7 @@ pub struct Course {
     /// Total completions
     pub total_completions: u32,
     
-    /// Total enrollments
+    /// Total enrollments ever created
     pub total_enrollments: u32,
     
     /// Accepts new enrollments
@@ -61,11 +63,35 @@ pub struct Course {
     /// Last update timestamp
     pub updated_at: i64,
     
+    /// Emergency freeze: no enrollments, progress or credentials
+    pub is_frozen: bool,
+    
+    /// Account layout version (see `SCHEMA_VERSION`)
+    pub schema_version: u8,
+    
+    /// Listed in the CourseIndex pages below
+    pub is_indexed: bool,
+    
+    /// Page of the global CourseIndex holding this course
+    pub global_index_page: u16,
+    
+    /// Page of the track CourseIndex holding this course
+    pub track_index_page: u16,
+    
     /// Reserved for future use
-    pub _reserved: [u8; 16],
+    pub _reserved: [u8; 9],
     
     /// PDA bump
     pub bump: u8,
+    
+    /// Enrollments neither finalized nor closed (v2)
+    pub active_enrollments: u32,
+    
+    /// Enrollments closed before completion (v2)
+    pub abandoned_enrollments: u32,
+    
+    /// Sum of enroll-to-finalize durations, in seconds (v2)
+    pub total_completion_time: u64,
 }
 
 impl Course {
@@ -90,10 +116,91 @@ impl Course {
         1 +  // is_active
         8 +  // created_at
         8 +  // updated_at
-        16 + // reserved
-        1;   // bump
+        1 +  // is_frozen
+        1 +  // schema_version
+        1 +  // is_indexed
+        2 +  // global_index_page
+        2 +  // track_index_page
+        9 +  // reserved
+        1 +  // bump
+        4 +  // active_enrollments
+        4 +  // abandoned_enrollments
+        8;   // total_completion_time
+    
+    /// Current account layout version
+    pub const SCHEMA_VERSION: u8 = 2;
+    
+    /// Upgrade an older layout in place
+    pub fn migrate(&mut self) -> Result<()> {
+        require!(
+            self.schema_version < Self::SCHEMA_VERSION,
+            AcademyError::AlreadyMigrated
+        );
+        // v0 -> v1: is_frozen and the index fields are carved from
+        // `_reserved` and decode as unfrozen / not yet indexed
+        // v1 -> v2: enrollment counters appended after `bump`, counted
+        // from migration onwards
+        self.schema_version = Self::SCHEMA_VERSION;
+        Ok(())
+    }
+    
+    /// Record a new enrollment
+    pub fn record_enrollment(&mut self) -> Result<()> {
+        self.total_enrollments = self
+            .total_enrollments
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        self.active_enrollments = self
+            .active_enrollments
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record a finalized enrollment that started at `enrolled_at`
+    pub fn record_completion(&mut self, enrolled_at: i64, now: i64) -> Result<()> {
+        self.total_completions = self
+            .total_completions
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        // Enrollments predating v2 were never counted as active
+        self.active_enrollments = self.active_enrollments.saturating_sub(1);
+        let elapsed = now.saturating_sub(enrolled_at).max(0) as u64;
+        self.total_completion_time = self
+            .total_completion_time
+            .checked_add(elapsed)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record an enrollment closed before completion
+    pub fn record_abandonment(&mut self) -> Result<()> {
+        self.abandoned_enrollments = self
+            .abandoned_enrollments
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        self.active_enrollments = self.active_enrollments.saturating_sub(1);
+        Ok(())
+    }
+    
+    /// Mean enroll-to-finalize time in seconds
+    pub fn average_completion_time(&self) -> Option<u64> {
+        if self.total_completions == 0 {
+            return None;
+        }
+        Some(self.total_completion_time / self.total_completions as u64)
+    }
+    
+    pub const SEED: &'static [u8] = b"course";
+    
+    /// `scope` is `Config::scope_seed` of the owning academy
+    pub fn seeds<'a>(scope: &'a [u8], course_id: &'a str) -> Vec<&'a [u8]> {
+        vec![Self::SEED, scope, course_id.as_bytes()]
+    }
     
-    pub fn seeds(course_id: &str) -> Vec<&[u8]> {
-        vec![b"course", course_id.as_bytes()]
+    /// Fail with `Paused` while the course is frozen
+    pub fn require_not_frozen(&self) -> Result<()> {
+        require!(!self.is_frozen, AcademyError::Paused);
+        Ok(())
     }
 }
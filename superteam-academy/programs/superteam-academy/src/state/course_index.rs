@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+
+/// One course in the catalogue
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct CourseIndexEntry {
+    /// Course PDA
+    pub course: Pubkey,
+    
+    /// Mirrors Course.is_active
+    pub is_active: bool,
+}
+
+impl CourseIndexEntry {
+    pub const SIZE: usize = 32 + // course
+        1;   // is_active
+}
+
+/// CourseIndex PDA - One page of the course catalogue
+/// Seeds: ["course_index", config, scope.to_le_bytes(), page.to_le_bytes()]
+#[account]
+pub struct CourseIndex {
+    /// Track id, or `GLOBAL` for the catalogue-wide index
+    pub scope: u16,
+    
+    /// Page number (pages fill in order)
+    pub page: u16,
+    
+    /// Courses on this page, in creation order
+    pub entries: Vec<CourseIndexEntry>,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CourseIndex {
+    /// Scope of the index listing every course
+    pub const GLOBAL: u16 = u16::MAX;
+    
+    pub const PAGE_SIZE: usize = 32;
+    
+    pub const SIZE: usize = 8 + // discriminator
+        2 +  // scope
+        2 +  // page
+        4 + Self::PAGE_SIZE * CourseIndexEntry::SIZE + // entries
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"course_index";
+    
+    /// No room left; the next page may be opened
+    pub fn is_full(&self) -> bool {
+        self.entries.len() >= Self::PAGE_SIZE
+    }
+    
+    /// Append `course` to this page
+    pub fn push(&mut self, course: Pubkey, is_active: bool) -> Result<()> {
+        require!(!self.is_full(), AcademyError::CourseIndexFull);
+        self.entries.push(CourseIndexEntry { course, is_active });
+        Ok(())
+    }
+    
+    /// Update the active flag of `course` on this page
+    pub fn set_active(&mut self, course: &Pubkey, is_active: bool) -> Result<()> {
+        let entry = self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.course == *course)
+            .ok_or(AcademyError::CourseNotIndexed)?;
+        entry.is_active = is_active;
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+
+/// CourseStats PDA - Per-lesson completion funnel for a course
+/// Seeds: ["course_stats", course]
+#[account]
+pub struct CourseStats {
+    /// Course PDA these stats belong to
+    pub course: Pubkey,
+    
+    /// Completions per lesson index
+    pub lesson_completions: [u32; 128],
+    
+    /// First lesson completion in this course
+    pub first_completion_at: Option<i64>,
+    
+    /// Most recent lesson completion in this course
+    pub last_completion_at: Option<i64>,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl CourseStats {
+    /// Lessons tracked by the Enrollment bitmap
+    pub const MAX_LESSONS: usize = 128;
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // course
+        4 * Self::MAX_LESSONS + // lesson_completions
+        9 +  // first_completion_at (Option<i64> = 1 + 8)
+        9 +  // last_completion_at (Option<i64> = 1 + 8)
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"course_stats";
+    
+    /// Record a completion of `lesson_index` at `now`
+    pub fn record_lesson(&mut self, lesson_index: u8, now: i64) -> Result<()> {
+        let count = self
+            .lesson_completions
+            .get_mut(lesson_index as usize)
+            .ok_or(AcademyError::LessonOutOfBounds)?;
+        *count = count.checked_add(1).ok_or(AcademyError::Overflow)?;
+        
+        if self.first_completion_at.is_none() {
+            self.first_completion_at = Some(now);
+        }
+        self.last_completion_at = Some(now);
+        Ok(())
+    }
+    
+    /// Undo a completion of `lesson_index` cleared by revoke_xp.
+    /// Saturates for completions made before the stats existed.
+    pub fn unrecord_lesson(&mut self, lesson_index: u8) -> Result<()> {
+        let count = self
+            .lesson_completions
+            .get_mut(lesson_index as usize)
+            .ok_or(AcademyError::LessonOutOfBounds)?;
+        *count = count.saturating_sub(1);
+        Ok(())
+    }
+}
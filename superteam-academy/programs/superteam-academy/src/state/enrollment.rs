@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::error::AcademyError;
+
 /// Enrollment PDA - User's course enrollment
-/// Seeds: ["enrollment", course_id.as_bytes(), user_pubkey]
+/// Seeds: ["enrollment", config, course_id.as_bytes(), user_pubkey] (config omitted for academy 0)
 #[account]
 pub struct Enrollment {
     /// The Course PDA this enrollment belongs to
@@ -25,8 +27,11 @@ pub struct Enrollment {
     /// Whether completion bonus claimed
     pub bonus_claimed: bool,
     
+    /// Account layout version (see `SCHEMA_VERSION`)
+    pub schema_version: u8,
+    
     /// Reserved
-    pub _reserved: [u8; 7],
+    pub _reserved: [u8; 6],
     
     /// PDA bump
     pub bump: u8,
@@ -41,16 +46,32 @@ impl Enrollment {
         32 + // lesson_flags ([u64; 4])
         33 + // credential_asset (Option<Pubkey> = 1 + 32)
         1 +  // bonus_claimed
-        7 +  // reserved
+        1 +  // schema_version
+        6 +  // reserved
         1;   // bump
     
-    pub fn course_seeds(course_id: &str) -> Vec<&[u8]> {
-        vec![b"course", course_id.as_bytes()]
+    /// Current account layout version
+    pub const SCHEMA_VERSION: u8 = 1;
+    
+    /// Upgrade an older layout in place
+    pub fn migrate(&mut self) -> Result<()> {
+        require!(
+            self.schema_version < Self::SCHEMA_VERSION,
+            AcademyError::AlreadyMigrated
+        );
+        // v0 -> v1: version byte only, no new fields
+        self.schema_version = Self::SCHEMA_VERSION;
+        Ok(())
     }
     
-    pub fn learner_seeds(course_id: &str, learner: &Pubkey) -> Vec<Vec<u8>> {
+    pub fn course_seeds<'a>(scope: &'a [u8], course_id: &'a str) -> Vec<&'a [u8]> {
+        vec![b"course", scope, course_id.as_bytes()]
+    }
+    
+    pub fn learner_seeds(scope: &[u8], course_id: &str, learner: &Pubkey) -> Vec<Vec<u8>> {
         vec![
             b"enrollment".to_vec(),
+            scope.to_vec(),
             course_id.as_bytes().to_vec(),
             learner.to_bytes().to_vec(),
         ]
@@ -80,6 +101,17 @@ impl Enrollment {
         !already_completed
     }
     
+    /// Clear a completed lesson (fraud clawback)
+    pub fn clear_lesson(&mut self, lesson_index: u8) -> bool {
+        if !self.is_lesson_completed(lesson_index) {
+            return false;
+        }
+        let word = (lesson_index / 64) as usize;
+        let bit = (lesson_index % 64) as u64;
+        self.lesson_flags[word] &= !(1u64 << bit);
+        true
+    }
+    
     /// Check if all lessons completed
     pub fn is_course_completed(&self, lesson_count: u8) -> bool {
         for i in 0..lesson_count {
@@ -1,7 +1,9 @@
 use anchor_lang::prelude::*;
 
+use crate::error::AcademyError;
+
 /// LearnerProfile PDA - User progress tracking
-/// Seeds: ["learner", user_pubkey]
+/// Seeds: ["learner", config, user_pubkey] (config omitted for academy 0)
 #[account]
 pub struct LearnerProfile {
     /// Learner wallet
@@ -34,11 +36,26 @@ pub struct LearnerProfile {
     /// Has referrer
     pub has_referrer: bool,
     
+    /// Season `season_xp` belongs to (reset lazily on season change)
+    pub xp_season: u16,
+    
+    /// Level on the Config XP curve
+    pub level: u16,
+    
+    /// Account layout version (see `SCHEMA_VERSION`)
+    pub schema_version: u8,
+    
     /// Reserved
-    pub _reserved: [u8; 16],
+    pub _reserved: [u8; 11],
     
     /// PDA bump
     pub bump: u8,
+    
+    /// XP earned across all seasons
+    pub lifetime_xp: u64,
+    
+    /// XP earned in `xp_season`
+    pub season_xp: u64,
 }
 
 impl LearnerProfile {
@@ -53,8 +70,30 @@ impl LearnerProfile {
         2 +  // last_xp_day
         2 +  // referral_count
         1 +  // has_referrer
-        16 + // reserved
-        1;   // bump
+        2 +  // xp_season
+        2 +  // level
+        1 +  // schema_version
+        11 + // reserved
+        1 +  // bump
+        8 +  // lifetime_xp
+        8;   // season_xp
+    
+    /// Current account layout version
+    pub const SCHEMA_VERSION: u8 = 1;
+    
+    /// Upgrade an older layout in place
+    pub fn migrate(&mut self) -> Result<()> {
+        require!(
+            self.schema_version < Self::SCHEMA_VERSION,
+            AcademyError::AlreadyMigrated
+        );
+        // v0 -> v1: xp_season/level are carved from `_reserved` and
+        // lifetime_xp/season_xp appended after `bump`, all decoding as 0.
+        // Totals count from migration onwards; earlier XP stays in the
+        // token balance only.
+        self.schema_version = Self::SCHEMA_VERSION;
+        Ok(())
+    }
     
     pub const SEED: &'static [u8] = b"learner";
     
@@ -71,4 +110,42 @@ impl LearnerProfile {
         let bit = (index % 64) as u64;
         self.achievement_flags[word] |= 1u64 << bit;
     }
+    
+    /// Clear a claimed achievement (fraud clawback)
+    pub fn unclaim_achievement(&mut self, index: u8) {
+        let word = (index / 64) as usize;
+        let bit = (index % 64) as u64;
+        self.achievement_flags[word] &= !(1u64 << bit);
+    }
+    
+    /// Add minted XP to lifetime and season totals.
+    /// `season` is the season the XP was minted in; season totals reset
+    /// lazily when `current_season` moves on.
+    pub fn credit_xp(&mut self, amount: u64, season: u16, current_season: u16) -> Result<()> {
+        if self.xp_season != current_season {
+            self.xp_season = current_season;
+            self.season_xp = 0;
+        }
+        
+        self.lifetime_xp = self
+            .lifetime_xp
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        
+        if season == current_season {
+            self.season_xp = self
+                .season_xp
+                .checked_add(amount)
+                .ok_or(AcademyError::Overflow)?;
+        }
+        Ok(())
+    }
+    
+    /// Remove clawed-back XP from lifetime and season totals
+    pub fn debit_xp(&mut self, amount: u64, season: u16) {
+        self.lifetime_xp = self.lifetime_xp.saturating_sub(amount);
+        if season == self.xp_season {
+            self.season_xp = self.season_xp.saturating_sub(amount);
+        }
+    }
 }
@@ -1,9 +1,31 @@
 pub mod config;
 pub mod course;
+pub mod course_stats;
+pub mod course_index;
+pub mod track;
+pub mod track_progress;
 pub mod learner;
 pub mod enrollment;
+pub mod season;
+pub mod season_reward_claim;
+pub mod multiplier_window;
+pub mod signer_registry;
+pub mod pending_config_change;
+pub mod admin_council;
+pub mod admin_proposal;
 
 pub use config::*;
 pub use course::*;
+pub use course_stats::*;
+pub use course_index::*;
+pub use track::*;
+pub use track_progress::*;
 pub use learner::*;
 pub use enrollment::*;
+pub use season::*;
+pub use season_reward_claim::*;
+pub use multiplier_window::*;
+pub use signer_registry::*;
+pub use pending_config_change::*;
+pub use admin_council::*;
+pub use admin_proposal::*;
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+
+/// MultiplierWindow PDA - Time-boxed XP boost ("double XP weekend")
+/// Seeds: ["multiplier", config, window_id.to_le_bytes()]
+#[account]
+pub struct MultiplierWindow {
+    /// Config PDA this window belongs to
+    pub config: Pubkey,
+    
+    /// Window identifier (chosen by authority)
+    pub window_id: u32,
+    
+    /// Window opens (inclusive)
+    pub starts_at: i64,
+    
+    /// Window closes (exclusive)
+    pub ends_at: i64,
+    
+    /// Multiplier in basis points (20000 = 2x)
+    pub multiplier_bps: u16,
+    
+    /// Only courses in this track (None = all tracks)
+    pub track_id: Option<u16>,
+    
+    /// Only this Course PDA (None = all courses)
+    pub course: Option<Pubkey>,
+    
+    /// Creation timestamp
+    pub created_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl MultiplierWindow {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // config
+        4 +  // window_id
+        8 +  // starts_at
+        8 +  // ends_at
+        2 +  // multiplier_bps
+        3 +  // track_id (Option<u16> = 1 + 2)
+        33 + // course (Option<Pubkey> = 1 + 32)
+        8 +  // created_at
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"multiplier";
+    
+    /// 1x in basis points
+    pub const BASE_BPS: u16 = 10_000;
+    
+    /// Highest allowed boost (5x)
+    pub const MAX_MULTIPLIER_BPS: u16 = 50_000;
+    
+    /// Whether this window boosts `course` at `now`
+    pub fn applies_to(&self, course_key: &Pubkey, track_id: u16, now: i64) -> bool {
+        if now < self.starts_at || now >= self.ends_at {
+            return false;
+        }
+        if let Some(filter) = self.track_id {
+            if filter != track_id {
+                return false;
+            }
+        }
+        if let Some(filter) = self.course {
+            if filter != *course_key {
+                return false;
+            }
+        }
+        true
+    }
+    
+    /// Boosted amount for `base_xp`
+    pub fn apply(&self, base_xp: u32) -> Result<u32> {
+        let boosted = (base_xp as u64)
+            .checked_mul(self.multiplier_bps as u64)
+            .ok_or(AcademyError::Overflow)?
+            / Self::BASE_BPS as u64;
+        u32::try_from(boosted).map_err(|_| AcademyError::Overflow.into())
+    }
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+use crate::instructions::{SetSignerParams, UpdateConfigParams};
+use crate::state::Config;
+
+/// Change a PendingConfigChange applies once its delay has passed
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub enum ConfigChange {
+    /// update_config
+    Config(UpdateConfigParams),
+    /// set_signer (removal stays immediate for emergencies)
+    SetSigner(SetSignerParams),
+    /// rotate_signer
+    RotateSigner {
+        old_signer: Pubkey,
+        next_signer: Pubkey,
+        rotation_deadline: i64,
+    },
+}
+
+impl ConfigChange {
+    /// Largest variant plus the enum tag
+    pub const SIZE: usize = 1 + 32 + 32 + 8; // RotateSigner
+}
+
+/// PendingConfigChange PDA - Timelocked update_config / signer change
+/// Seeds: ["config_change", config, change_id.to_le_bytes()]
+#[account]
+pub struct PendingConfigChange {
+    /// Sequential change identifier
+    pub change_id: u32,
+    
+    /// Queued change
+    pub change: ConfigChange,
+    
+    /// Authority that queued the change
+    pub proposed_by: Pubkey,
+    
+    /// Receives the rent back on execute / cancel
+    pub rent_payer: Pubkey,
+    
+    /// Queue timestamp
+    pub queued_at: i64,
+    
+    /// Earliest execution time
+    pub execute_after: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PendingConfigChange {
+    pub const SIZE: usize = 8 + // discriminator
+        4 +  // change_id
+        ConfigChange::SIZE + // change
+        32 + // proposed_by
+        32 + // rent_payer
+        8 +  // queued_at
+        8 +  // execute_after
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"config_change";
+    
+    /// Queue `change` behind the config timelock; returns the execute-after time
+    pub fn queue(
+        &mut self,
+        config: &mut Config,
+        change: ConfigChange,
+        proposed_by: Pubkey,
+        rent_payer: Pubkey,
+        bump: u8,
+        now: i64,
+    ) -> Result<i64> {
+        let execute_after = now
+            .checked_add(config.config_timelock)
+            .ok_or(AcademyError::Overflow)?;
+        
+        self.change_id = config.next_config_change_id;
+        self.change = change;
+        self.proposed_by = proposed_by;
+        self.rent_payer = rent_payer;
+        self.queued_at = now;
+        self.execute_after = execute_after;
+        self.bump = bump;
+        
+        config.next_config_change_id = config
+            .next_config_change_id
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(execute_after)
+    }
+}
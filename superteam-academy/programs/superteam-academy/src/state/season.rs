@@ -0,0 +1,139 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+
+/// Season PDA - Per-season archive record
+/// Seeds: ["season", config, season.to_le_bytes()]
+#[account]
+pub struct Season {
+    /// Season number
+    pub season: u16,
+    
+    /// Season's Token-2022 XP mint address
+    pub mint: Pubkey,
+    
+    /// Season start timestamp
+    pub started_at: i64,
+    
+    /// Scheduled end timestamp (None = closed manually)
+    pub ends_at: Option<i64>,
+    
+    /// Season end timestamp (set by close_season)
+    pub ended_at: Option<i64>,
+    
+    /// Total XP minted during this season
+    pub total_xp_minted: u64,
+    
+    /// Total XP clawed back from this season's mint
+    pub total_xp_revoked: u64,
+    
+    /// Lessons completed during this season
+    pub lessons_completed: u64,
+    
+    /// Courses finalized during this season
+    pub courses_finalized: u32,
+    
+    /// Whether the season has been closed
+    pub is_closed: bool,
+    
+    /// Merkle root of final standings (learner, rank, season XP, reward)
+    pub leaderboard_root: Option<[u8; 32]>,
+    
+    /// Mint prizes are paid in (season XP mint or an SPL token)
+    pub reward_mint: Pubkey,
+    
+    /// Number of prizes claimed
+    pub rewards_claimed: u32,
+    
+    /// Total prize amount paid out
+    pub rewards_paid: u64,
+    
+    /// Reserved for future use
+    pub _reserved: [u8; 15],
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Season {
+    pub const SIZE: usize = 8 + // discriminator
+        2 +  // season
+        32 + // mint
+        8 +  // started_at
+        9 +  // ends_at (Option<i64> = 1 + 8)
+        9 +  // ended_at (Option<i64> = 1 + 8)
+        8 +  // total_xp_minted
+        8 +  // total_xp_revoked
+        8 +  // lessons_completed
+        4 +  // courses_finalized
+        1 +  // is_closed
+        33 + // leaderboard_root (Option<[u8; 32]> = 1 + 32)
+        32 + // reward_mint
+        4 +  // rewards_claimed
+        8 +  // rewards_paid
+        15 + // reserved
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"season";
+    
+    /// Record XP minted this season
+    pub fn record_xp(&mut self, amount: u64) -> Result<()> {
+        self.total_xp_minted = self
+            .total_xp_minted
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record XP clawed back from this season
+    pub fn record_revoked(&mut self, amount: u64) -> Result<()> {
+        self.total_xp_revoked = self
+            .total_xp_revoked
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record a lesson completion this season
+    pub fn record_lesson(&mut self) -> Result<()> {
+        self.lessons_completed = self
+            .lessons_completed
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Commit final standings (once, after close)
+    pub fn commit_leaderboard(&mut self, root: [u8; 32], reward_mint: Pubkey) -> Result<()> {
+        require!(self.is_closed, AcademyError::SeasonNotClosed);
+        require!(
+            self.leaderboard_root.is_none(),
+            AcademyError::LeaderboardAlreadyCommitted
+        );
+        self.leaderboard_root = Some(root);
+        self.reward_mint = reward_mint;
+        Ok(())
+    }
+    
+    /// Record a paid prize
+    pub fn record_reward(&mut self, amount: u64) -> Result<()> {
+        self.rewards_claimed = self
+            .rewards_claimed
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        self.rewards_paid = self
+            .rewards_paid
+            .checked_add(amount)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Record a course finalization this season
+    pub fn record_course_finalized(&mut self) -> Result<()> {
+        self.courses_finalized = self
+            .courses_finalized
+            .checked_add(1)
+            .ok_or(AcademyError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+/// SeasonRewardClaim PDA - Receipt preventing double prize claims
+/// Seeds: ["season_reward", config, season.to_le_bytes(), learner_pubkey]
+#[account]
+pub struct SeasonRewardClaim {
+    /// Season number
+    pub season: u16,
+    
+    /// Learner who claimed
+    pub learner: Pubkey,
+    
+    /// Final rank
+    pub rank: u16,
+    
+    /// Season XP at close
+    pub season_xp: u64,
+    
+    /// Prize amount paid
+    pub amount: u64,
+    
+    /// Claim timestamp
+    pub claimed_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SeasonRewardClaim {
+    pub const SIZE: usize = 8 + // discriminator
+        2 +  // season
+        32 + // learner
+        2 +  // rank
+        8 +  // season_xp
+        8 +  // amount
+        8 +  // claimed_at
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"season_reward";
+}
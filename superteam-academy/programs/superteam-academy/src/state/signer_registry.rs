@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+use crate::instructions::SetSignerParams;
+
+/// One backend key and what it may do
+#[derive(Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct SignerEntry {
+    /// Signer public key
+    pub signer: Pubkey,
+    
+    /// Permission bitflags (SignerRegistry::PERM_*)
+    pub permissions: u8,
+    
+    /// Max XP this signer may mint per day (0 = unlimited)
+    pub daily_xp_quota: u32,
+    
+    /// XP minted by this signer on `quota_day`
+    pub xp_today: u32,
+    
+    /// Day number of `xp_today` (unix_ts / 86400)
+    pub quota_day: u16,
+    
+    /// Key stops being accepted at this time (rotation overlap)
+    pub expires_at: Option<i64>,
+}
+
+impl SignerEntry {
+    pub const SIZE: usize = 32 + // signer
+        1 +  // permissions
+        4 +  // daily_xp_quota
+        4 +  // xp_today
+        2 +  // quota_day
+        9;   // expires_at (Option<i64> = 1 + 8)
+    
+    /// Entry for the key replacing this one: same roles and quota, and the
+    /// day's usage so far, so a rotation does not reset the daily cap
+    pub fn successor(&self, signer: Pubkey) -> SignerEntry {
+        SignerEntry {
+            signer,
+            permissions: self.permissions,
+            daily_xp_quota: self.daily_xp_quota,
+            xp_today: self.xp_today,
+            quota_day: self.quota_day,
+            expires_at: None,
+        }
+    }
+    
+    /// Whether the key is still accepted at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now < expires_at,
+            None => true,
+        }
+    }
+}
+
+/// SignerRegistry PDA - Role-scoped backend signer set
+/// Seeds: ["signer_registry", config]
+#[account]
+pub struct SignerRegistry {
+    /// Registered signers
+    pub signers: Vec<SignerEntry>,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SignerRegistry {
+    pub const MAX_SIGNERS: usize = 16;
+    
+    pub const SIZE: usize = 8 + // discriminator
+        4 + Self::MAX_SIGNERS * SignerEntry::SIZE + // signers
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"signer_registry";
+    
+    pub const PERM_COMPLETE_LESSON: u8 = 1 << 0;
+    pub const PERM_ACHIEVEMENTS: u8 = 1 << 1;
+    pub const PERM_STREAK_FREEZE: u8 = 1 << 2;
+    pub const PERM_CREDENTIALS: u8 = 1 << 3;
+    pub const PERM_FINALIZE: u8 = 1 << 4;
+    pub const PERM_REVOKE_XP: u8 = 1 << 5;
+    pub const PERM_ALL: u8 = (1 << 6) - 1;
+    
+    /// Position of `signer` in the registry
+    pub fn find(&self, signer: &Pubkey) -> Option<usize> {
+        self.signers.iter().position(|entry| entry.signer == *signer)
+    }
+    
+    /// Check `signer` is active and holds `permission`; returns its index
+    pub fn check(&self, signer: &Pubkey, permission: u8, now: i64) -> Result<usize> {
+        let index = self.find(signer).ok_or(AcademyError::Unauthorized)?;
+        let entry = &self.signers[index];
+        
+        require!(entry.is_active(now), AcademyError::Unauthorized);
+        require!(
+            entry.permissions & permission == permission,
+            AcademyError::Unauthorized
+        );
+        Ok(index)
+    }
+    
+    /// Charge `xp` to the daily quota of the signer at `index`
+    pub fn charge_quota(&mut self, index: usize, xp: u32, now: i64) -> Result<()> {
+        let entry = &mut self.signers[index];
+        if entry.daily_xp_quota == 0 {
+            return Ok(());
+        }
+        
+        let today = (now / 86400) as u16;
+        if today > entry.quota_day {
+            entry.xp_today = 0;
+            entry.quota_day = today;
+        }
+        
+        let new_total = entry
+            .xp_today
+            .checked_add(xp)
+            .ok_or(AcademyError::Overflow)?;
+        require!(
+            new_total <= entry.daily_xp_quota,
+            AcademyError::SignerQuotaExceeded
+        );
+        entry.xp_today = new_total;
+        Ok(())
+    }
+    
+    /// Check `signer` holds `permission` and charge `xp` to its daily quota
+    pub fn authorize(
+        &mut self,
+        signer: &Pubkey,
+        permission: u8,
+        xp: u32,
+        now: i64,
+    ) -> Result<()> {
+        let index = self.check(signer, permission, now)?;
+        self.charge_quota(index, xp, now)
+    }
+    
+    /// Drop keys whose rotation deadline has passed
+    pub fn prune_expired(&mut self, now: i64) {
+        self.signers.retain(|entry| entry.is_active(now));
+    }
+    
+    /// Add a signer or change its permissions / quota (set_signer)
+    pub fn set_signer(&mut self, params: &SetSignerParams, now: i64) -> Result<()> {
+        require!(
+            params.permissions & !Self::PERM_ALL == 0,
+            AcademyError::InvalidPermissions
+        );
+        if let Some(expires_at) = params.expires_at {
+            require!(expires_at > now, AcademyError::InvalidRotationDeadline);
+        }
+        
+        self.prune_expired(now);
+        
+        match self.find(&params.signer) {
+            Some(index) => {
+                // Usage counters survive permission / quota changes
+                let entry = &mut self.signers[index];
+                entry.permissions = params.permissions;
+                entry.daily_xp_quota = params.daily_xp_quota;
+                entry.expires_at = params.expires_at;
+            }
+            None => {
+                require!(
+                    self.signers.len() < Self::MAX_SIGNERS,
+                    AcademyError::SignerRegistryFull
+                );
+                self.signers.push(SignerEntry {
+                    signer: params.signer,
+                    permissions: params.permissions,
+                    daily_xp_quota: params.daily_xp_quota,
+                    xp_today: 0,
+                    quota_day: 0,
+                    expires_at: params.expires_at,
+                });
+            }
+        }
+        Ok(())
+    }
+    
+    /// Start rotating `old_signer` to `next_signer` (rotate_signer)
+    pub fn rotate(
+        &mut self,
+        old_signer: &Pubkey,
+        next_signer: Pubkey,
+        rotation_deadline: i64,
+        now: i64,
+    ) -> Result<()> {
+        require!(rotation_deadline > now, AcademyError::InvalidRotationDeadline);
+        
+        self.prune_expired(now);
+        
+        let index = self.find(old_signer).ok_or(AcademyError::SignerNotFound)?;
+        require!(
+            self.signers[index].expires_at.is_none(),
+            AcademyError::RotationInProgress
+        );
+        require!(
+            self.find(&next_signer).is_none(),
+            AcademyError::SignerAlreadyRegistered
+        );
+        require!(
+            self.signers.len() < Self::MAX_SIGNERS,
+            AcademyError::SignerRegistryFull
+        );
+        
+        // New key inherits the old key's roles and today's usage; old key is
+        // dropped at the deadline
+        let old = &mut self.signers[index];
+        old.expires_at = Some(rotation_deadline);
+        let next = old.successor(next_signer);
+        self.signers.push(next);
+        Ok(())
+    }
+}
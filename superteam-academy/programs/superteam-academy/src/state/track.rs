@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+
+/// Track PDA - Learning track registry entry
+/// Seeds: ["track", config, track_id.to_le_bytes()]
+#[account]
+pub struct Track {
+    /// Track identifier (referenced by Course.track_id)
+    pub track_id: u16,
+    
+    /// Display name used for credentials (max 32 chars)
+    pub name: String,
+    
+    /// Off-chain description metadata (max 200 chars)
+    pub description_uri: String,
+    
+    /// Highest course level in this track
+    pub max_level: u8,
+    
+    /// Credential display name of each level (index 0 = level 1)
+    pub level_names: Vec<String>,
+    
+    /// Metaplex Core collection for this track's credentials
+    pub collection: Pubkey,
+    
+    /// Accepts new courses
+    pub is_active: bool,
+    
+    /// Creation timestamp
+    pub created_at: i64,
+    
+    /// Last update timestamp
+    pub updated_at: i64,
+    
+    /// Reserved for future use
+    pub _reserved: [u8; 16],
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl Track {
+    pub const MAX_NAME_LEN: usize = 32;
+    pub const MAX_URI_LEN: usize = 200;
+    pub const MAX_LEVELS: usize = 8;
+    pub const MAX_LEVEL_NAME_LEN: usize = 24;
+    
+    pub const SIZE: usize = 8 + // discriminator
+        2 +  // track_id
+        4 + Self::MAX_NAME_LEN + // name
+        4 + Self::MAX_URI_LEN +  // description_uri
+        1 +  // max_level
+        4 + Self::MAX_LEVELS * (4 + Self::MAX_LEVEL_NAME_LEN) + // level_names
+        32 + // collection
+        1 +  // is_active
+        8 +  // created_at
+        8 +  // updated_at
+        16 + // reserved
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"track";
+    
+    /// Validate name, URI, level cap and one name per level
+    pub fn validate(
+        name: &str,
+        description_uri: &str,
+        max_level: u8,
+        level_names: &[String],
+    ) -> Result<()> {
+        require!(name.len() <= Self::MAX_NAME_LEN, AcademyError::TrackNameTooLong);
+        require!(
+            description_uri.len() <= Self::MAX_URI_LEN,
+            AcademyError::MetadataUriTooLong
+        );
+        require!(
+            max_level >= 1 && max_level as usize <= Self::MAX_LEVELS,
+            AcademyError::InvalidTrackLevel
+        );
+        require!(
+            level_names.len() == max_level as usize
+                && level_names
+                    .iter()
+                    .all(|level| !level.is_empty() && level.len() <= Self::MAX_LEVEL_NAME_LEN),
+            AcademyError::InvalidLevelNames
+        );
+        Ok(())
+    }
+    
+    /// Display name of `level` ("Level N" outside the named range)
+    pub fn level_name(&self, level: u8) -> String {
+        level
+            .checked_sub(1)
+            .and_then(|index| self.level_names.get(index as usize))
+            .cloned()
+            .unwrap_or_else(|| format!("Level {}", level))
+    }
+    
+    /// Ensure a new course at `level` may join this track
+    pub fn require_accepts(&self, level: u8) -> Result<()> {
+        require!(self.is_active, AcademyError::TrackNotActive);
+        require!(
+            level >= 1 && level <= self.max_level,
+            AcademyError::InvalidTrackLevel
+        );
+        Ok(())
+    }
+}
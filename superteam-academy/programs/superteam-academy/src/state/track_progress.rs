@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::error::AcademyError;
+
+/// TrackProgress PDA - Learner's progress through one track
+/// Seeds: ["track_progress", config, learner, track_id.to_le_bytes()]
+#[account]
+pub struct TrackProgress {
+    /// Learner wallet
+    pub learner: Pubkey,
+    
+    /// Track identifier
+    pub track_id: u16,
+    
+    /// Credential NFT for this track (set on first issue)
+    pub credential_asset: Option<Pubkey>,
+    
+    /// Highest track level completed
+    pub highest_level: u8,
+    
+    /// Course PDAs credited to this track, in completion order
+    pub completed_courses: Vec<Pubkey>,
+    
+    /// Last credited completion timestamp
+    pub updated_at: i64,
+    
+    /// When the credential was revoked (blocks further upgrades)
+    pub revoked_at: Option<i64>,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TrackProgress {
+    pub const MAX_COURSES: usize = 32;
+    
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // learner
+        2 +  // track_id
+        33 + // credential_asset (Option<Pubkey> = 1 + 32)
+        1 +  // highest_level
+        4 + Self::MAX_COURSES * 32 + // completed_courses
+        8 +  // updated_at
+        9 +  // revoked_at (Option<i64> = 1 + 8)
+        1;   // bump
+    
+    pub const SEED: &'static [u8] = b"track_progress";
+    
+    /// Set up a new progress account. A credential minted for this
+    /// enrollment before TrackProgress existed becomes the track credential,
+    /// so it is upgraded rather than minted again.
+    pub fn init(&mut self, learner: Pubkey, track_id: u16, legacy_asset: Option<Pubkey>, bump: u8) {
+        self.learner = learner;
+        self.track_id = track_id;
+        self.credential_asset = legacy_asset;
+        self.bump = bump;
+    }
+    
+    /// Number of courses credited to this track
+    pub fn courses_completed(&self) -> u16 {
+        self.completed_courses.len() as u16
+    }
+    
+    /// Credit a completed `course` at `level`, once per course
+    pub fn record_course(&mut self, course: Pubkey, level: u8, now: i64) -> Result<()> {
+        require!(
+            !self.completed_courses.contains(&course),
+            AcademyError::CourseAlreadyCredited
+        );
+        require!(
+            self.completed_courses.len() < Self::MAX_COURSES,
+            AcademyError::TrackProgressFull
+        );
+        self.completed_courses.push(course);
+        self.highest_level = self.highest_level.max(level);
+        self.updated_at = now;
+        Ok(())
+    }
+}
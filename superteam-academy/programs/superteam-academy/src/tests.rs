@@ -1,6 +1,14 @@
 use anchor_lang::{prelude::*, system_program};
 use crate::state::*;
+use crate::instructions::{SetSignerParams, UpdateConfigParams};
 use crate::error::AcademyError;
+use crate::utils::{
+    add_to_course_index, clear_credential_revocation, credential_level, mark_credential_revoked,
+    record_credential_completion, season_reward_leaf, upsert_attribute, verify_merkle_proof,
+    xp_mint_len, xp_mint_name, XP_MINT_EXTENSIONS,
+};
+use mpl_core::types::Attribute;
+use anchor_spl::token_2022::spl_token_2022::{extension::ExtensionType, state::Mint as MintState};
 
 #[cfg(test)]
 mod tests {
@@ -9,15 +17,26 @@ mod tests {
     // Helper untuk setup Config
     fn setup_config() -> Config {
         Config {
+            academy_id: 0,
             authority: Pubkey::new_from_array([0u8; 32]),
+            pending_authority: None,
             backend_signer: Pubkey::new_from_array([1u8; 32]),
             current_season: 1,
             current_mint: Pubkey::new_from_array([2u8; 32]),
             season_closed: false,
             season_started_at: 1000000000,
+            season_ends_at: None,
             max_daily_xp: 2000,
             max_achievement_xp: 500,
-            _reserved: [0; 32],
+            level_base_xp: 100,
+            level_growth_bps: 15_000,
+            pauser: Pubkey::new_from_array([0u8; 32]),
+            council: Pubkey::new_from_array([0u8; 32]),
+            paused: 0,
+            config_timelock: 0,
+            next_config_change_id: 0,
+            schema_version: 1,
+            _reserved: [0; 10],
             bump: 255,
         }
     }
@@ -42,10 +61,18 @@ mod tests {
             total_completions: 0,
             total_enrollments: 0,
             is_active: true,
+            is_frozen: false,
             created_at: 1000000000,
             updated_at: 1000000000,
-            _reserved: [0; 16],
+            schema_version: 2,
+            is_indexed: false,
+            global_index_page: 0,
+            track_index_page: 0,
+            _reserved: [0; 9],
             bump: 255,
+            active_enrollments: 0,
+            abandoned_enrollments: 0,
+            total_completion_time: 0,
         }
     }
     
@@ -62,7 +89,12 @@ mod tests {
             last_xp_day: 11574, // 1000000000 / 86400
             referral_count: 0,
             has_referrer: false,
-            _reserved: [0; 16],
+            lifetime_xp: 0,
+            season_xp: 0,
+            xp_season: 1,
+            level: 0,
+            schema_version: 1,
+            _reserved: [0; 11],
             bump: 255,
         }
     }
@@ -77,7 +109,30 @@ mod tests {
             lesson_flags: [0, 0, 0, 0],
             credential_asset: None,
             bonus_claimed: false,
-            _reserved: [0; 7],
+            schema_version: 1,
+            _reserved: [0; 6],
+            bump: 255,
+        }
+    }
+    
+    // Helper untuk setup Season
+    fn setup_season() -> Season {
+        Season {
+            season: 1,
+            mint: Pubkey::new_from_array([2u8; 32]),
+            started_at: 1000000000,
+            ends_at: None,
+            ended_at: None,
+            total_xp_minted: 0,
+            total_xp_revoked: 0,
+            lessons_completed: 0,
+            courses_finalized: 0,
+            is_closed: false,
+            leaderboard_root: None,
+            reward_mint: Pubkey::default(),
+            rewards_claimed: 0,
+            rewards_paid: 0,
+            _reserved: [0; 15],
             bump: 255,
         }
     }
@@ -126,6 +181,22 @@ mod tests {
         assert_eq!(enrollment.completed_lessons(), course.lesson_count);
     }
     
+    #[test]
+    fn test_clear_lesson() {
+        let mut enrollment = setup_enrollment();
+        
+        enrollment.complete_lesson(3);
+        enrollment.complete_lesson(70);
+        
+        assert!(enrollment.clear_lesson(70));
+        assert!(!enrollment.is_lesson_completed(70));
+        assert!(enrollment.is_lesson_completed(3));
+        
+        // Clearing an incomplete lesson is a no-op
+        assert!(!enrollment.clear_lesson(70));
+        assert_eq!(enrollment.completed_lessons(), 1);
+    }
+    
     #[test]
     fn test_lesson_out_of_bounds() {
         let mut enrollment = setup_enrollment();
@@ -176,6 +247,45 @@ mod tests {
         assert_eq!(total, 8);
     }
     
+    #[test]
+    fn test_credit_xp_resets_season_lazily() {
+        let mut learner = setup_learner_profile();
+        
+        learner.credit_xp(300, 1, 1).unwrap();
+        assert_eq!(learner.lifetime_xp, 300);
+        assert_eq!(learner.season_xp, 300);
+        
+        // Season rolled over: counter resets on next credit
+        learner.credit_xp(50, 2, 2).unwrap();
+        assert_eq!(learner.lifetime_xp, 350);
+        assert_eq!(learner.season_xp, 50);
+        assert_eq!(learner.xp_season, 2);
+        
+        // Past-season prize counts toward lifetime only
+        learner.credit_xp(1000, 1, 2).unwrap();
+        assert_eq!(learner.lifetime_xp, 1350);
+        assert_eq!(learner.season_xp, 50);
+    }
+    
+    #[test]
+    fn test_revoke_learner_xp() {
+        let mut learner = setup_learner_profile();
+        
+        learner.claim_achievement(65);
+        learner.unclaim_achievement(65);
+        assert!(!learner.is_achievement_claimed(65));
+        
+        learner.credit_xp(500, 1, 1).unwrap();
+        learner.debit_xp(200, 1);
+        assert_eq!(learner.lifetime_xp, 300);
+        assert_eq!(learner.season_xp, 300);
+        
+        // Past-season clawback only touches lifetime, never underflows
+        learner.debit_xp(1000, 0);
+        assert_eq!(learner.lifetime_xp, 0);
+        assert_eq!(learner.season_xp, 300);
+    }
+    
     #[test]
     fn test_unclaimed_achievement() {
         let learner = setup_learner_profile();
@@ -197,11 +307,211 @@ mod tests {
         assert!(Config::SIZE > 100);
     }
     
+    #[test]
+    fn test_season_active_window() {
+        let mut config = setup_config();
+        
+        // No scheduled end: open until closed
+        assert!(config.require_season_active(2000000000).is_ok());
+        
+        // Scheduled end: minting refused at and after the deadline
+        config.season_ends_at = Some(1500000000);
+        assert!(config.require_season_active(1499999999).is_ok());
+        assert!(config.require_season_active(1500000000).is_err());
+        
+        // Closed season always refused
+        config.season_ends_at = None;
+        config.season_closed = true;
+        assert!(config.require_season_active(1000000000).is_err());
+    }
+    
+    #[test]
+    fn test_pause_flags() {
+        let mut config = setup_config();
+        assert!(config.require_not_paused(Config::PAUSE_LESSONS).is_ok());
+        
+        // Categories pause independently
+        config.paused = Config::PAUSE_LESSONS | Config::PAUSE_REFERRALS;
+        assert!(config.require_not_paused(Config::PAUSE_LESSONS).is_err());
+        assert!(config.require_not_paused(Config::PAUSE_REFERRALS).is_err());
+        assert!(config.require_not_paused(Config::PAUSE_ENROLLMENT).is_ok());
+        
+        // Course freeze
+        let mut course = setup_course();
+        assert!(course.require_not_frozen().is_ok());
+        course.is_frozen = true;
+        assert!(course.require_not_frozen().is_err());
+        
+        // Authority and pauser may toggle
+        config.pauser = Pubkey::new_from_array([8u8; 32]);
+        assert!(config.is_pauser(&config.authority));
+        assert!(config.is_pauser(&Pubkey::new_from_array([8u8; 32])));
+        assert!(!config.is_pauser(&config.backend_signer));
+    }
+    
+    #[test]
+    fn test_config_change_params() {
+        let mut config = setup_config();
+        let full = UpdateConfigParams {
+            max_daily_xp: Some(3000),
+            max_achievement_xp: Some(600),
+            level_base_xp: Some(200),
+            level_growth_bps: Some(12_000),
+            pauser: Some(Pubkey::new_from_array([8u8; 32])),
+            config_timelock: Some(86400),
+        };
+        
+        // Fully populated params fit the queued account
+        assert_eq!(full.try_to_vec().unwrap().len(), UpdateConfigParams::SIZE);
+        
+        // Every queued change variant fits the pending account
+        let signer_change = SetSignerParams {
+            signer: Pubkey::new_from_array([2u8; 32]),
+            permissions: SignerRegistry::PERM_ALL,
+            daily_xp_quota: 5000,
+            expires_at: Some(i64::MAX),
+        };
+        let rotation = ConfigChange::RotateSigner {
+            old_signer: Pubkey::new_from_array([2u8; 32]),
+            next_signer: Pubkey::new_from_array([3u8; 32]),
+            rotation_deadline: i64::MAX,
+        };
+        for change in [
+            ConfigChange::Config(full.clone()),
+            ConfigChange::SetSigner(signer_change),
+            rotation,
+        ] {
+            assert!(change.try_to_vec().unwrap().len() <= ConfigChange::SIZE);
+        }
+        
+        // Only set fields are written
+        let partial = UpdateConfigParams {
+            max_daily_xp: Some(3000),
+            max_achievement_xp: None,
+            level_base_xp: None,
+            level_growth_bps: None,
+            pauser: None,
+            config_timelock: Some(86400),
+        };
+        let updated = partial.apply(&mut config).unwrap();
+        assert_eq!(updated, vec!["max_daily_xp", "config_timelock"]);
+        assert_eq!(config.max_daily_xp, 3000);
+        assert_eq!(config.max_achievement_xp, 500);
+        assert_eq!(config.config_timelock, 86400);
+        
+        let negative = UpdateConfigParams {
+            config_timelock: Some(-1),
+            ..partial
+        };
+        assert!(negative.apply(&mut config).is_err());
+    }
+    
+    #[test]
+    fn test_level_curve() {
+        let mut config = setup_config();
+        
+        // base 100, growth 1.5x: thresholds 100, 250, 475, ...
+        assert_eq!(config.level_for_xp(0), 0);
+        assert_eq!(config.level_for_xp(99), 0);
+        assert_eq!(config.level_for_xp(100), 1);
+        assert_eq!(config.level_for_xp(249), 1);
+        assert_eq!(config.level_for_xp(250), 2);
+        assert_eq!(config.level_for_xp(475), 3);
+        
+        // Flat curve: every level costs the same
+        config.level_growth_bps = 10_000;
+        assert_eq!(config.level_for_xp(1000), 10);
+        
+        // Capped at MAX_LEVEL
+        assert_eq!(config.level_for_xp(u64::MAX), Config::MAX_LEVEL);
+        
+        // Levels disabled
+        config.level_base_xp = 0;
+        assert_eq!(config.level_for_xp(1_000_000), 0);
+    }
+    
     #[test]
     fn test_config_seeds() {
         assert_eq!(Config::SEED, b"config");
     }
     
+    #[test]
+    fn test_academies_are_isolated() {
+        let (academy_a, _) = Pubkey::find_program_address(
+            &[Config::SEED, &Config::academy_seed(1)],
+            &crate::ID,
+        );
+        let (academy_b, _) = Pubkey::find_program_address(
+            &[Config::SEED, &Config::academy_seed(2)],
+            &crate::ID,
+        );
+        assert_ne!(academy_a, academy_b);
+        
+        let mut config_a = setup_config();
+        config_a.academy_id = 1;
+        let mut config_b = setup_config();
+        config_b.academy_id = 2;
+        let scope_a = config_a.scope_seed(&academy_a);
+        let scope_b = config_b.scope_seed(&academy_b);
+        
+        // Same course id and learner resolve to different PDAs per academy
+        let learner = Pubkey::new_from_array([9u8; 32]);
+        let (course_a, _) = Pubkey::find_program_address(
+            &Course::seeds(&scope_a, "anchor-beginner"),
+            &crate::ID,
+        );
+        let (course_b, _) = Pubkey::find_program_address(
+            &Course::seeds(&scope_b, "anchor-beginner"),
+            &crate::ID,
+        );
+        assert_ne!(course_a, course_b);
+        
+        let (profile_a, _) = Pubkey::find_program_address(
+            &[LearnerProfile::SEED, &scope_a, learner.as_ref()],
+            &crate::ID,
+        );
+        let (profile_b, _) = Pubkey::find_program_address(
+            &[LearnerProfile::SEED, &scope_b, learner.as_ref()],
+            &crate::ID,
+        );
+        assert_ne!(profile_a, profile_b);
+    }
+    
+    #[test]
+    fn test_academy_zero_keeps_legacy_addresses() {
+        let course_id = "anchor-beginner";
+        let learner = Pubkey::new_from_array([9u8; 32]);
+        let pda = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID).0;
+        
+        // Addresses as derived before academies existed
+        let legacy_config = pda(&[b"config"]);
+        let legacy_course = pda(&[b"course", course_id.as_bytes()]);
+        let legacy_profile = pda(&[b"learner", learner.as_ref()]);
+        let legacy_enrollment = pda(&[b"enrollment", course_id.as_bytes(), learner.as_ref()]);
+        
+        // Academy 0 reaches them through the scoped seed layout
+        let config_key = pda(&[Config::SEED, &Config::academy_seed(0)]);
+        assert_eq!(config_key, legacy_config);
+        
+        let mut config = setup_config();
+        config.academy_id = 0;
+        let scope = config.scope_seed(&config_key);
+        assert!(scope.is_empty());
+        assert_eq!(pda(&Course::seeds(&scope, course_id)), legacy_course);
+        assert_eq!(pda(&[LearnerProfile::SEED, &scope, learner.as_ref()]), legacy_profile);
+        let enrollment_seeds = Enrollment::learner_seeds(&scope, course_id, &learner);
+        let enrollment_seeds: Vec<&[u8]> = enrollment_seeds.iter().map(|s| s.as_slice()).collect();
+        assert_eq!(pda(&enrollment_seeds), legacy_enrollment);
+        
+        // Any other academy gets its own addresses
+        config.academy_id = 1;
+        let config_key = pda(&[Config::SEED, &Config::academy_seed(1)]);
+        assert_ne!(config_key, legacy_config);
+        let scope = config.scope_seed(&config_key);
+        assert_ne!(pda(&Course::seeds(&scope, course_id)), legacy_course);
+        assert_ne!(pda(&[LearnerProfile::SEED, &scope, learner.as_ref()]), legacy_profile);
+    }
+    
     // ═══════════════════════════════════════════════════════════════
     // TESTS: Course Validation
     // ═══════════════════════════════════════════════════════════════
@@ -209,10 +519,12 @@ mod tests {
     #[test]
     fn test_course_seeds() {
         let course_id = "anchor-beginner";
-        let seeds = Course::seeds(course_id);
-        assert_eq!(seeds.len(), 2);
+        let config = Pubkey::new_from_array([7u8; 32]);
+        let seeds = Course::seeds(config.as_ref(), course_id);
+        assert_eq!(seeds.len(), 3);
         assert_eq!(seeds[0], b"course");
-        assert_eq!(seeds[1], course_id.as_bytes());
+        assert_eq!(seeds[1], config.as_ref());
+        assert_eq!(seeds[2], course_id.as_bytes());
     }
     
     #[test]
@@ -222,6 +534,288 @@ mod tests {
         assert!(Course::SIZE > 180);
     }
     
+    #[test]
+    fn test_course_enrollment_counters() {
+        let mut course = setup_course();
+        assert_eq!(course.average_completion_time(), None);
+        
+        for _ in 0..3 {
+            course.record_enrollment().unwrap();
+        }
+        assert_eq!(course.total_enrollments, 3);
+        assert_eq!(course.active_enrollments, 3);
+        
+        // Two finish (1 day and 3 days), one gives up
+        course.record_completion(0, 86_400).unwrap();
+        course.record_completion(0, 3 * 86_400).unwrap();
+        course.record_abandonment().unwrap();
+        
+        assert_eq!(course.total_completions, 2);
+        assert_eq!(course.abandoned_enrollments, 1);
+        assert_eq!(course.active_enrollments, 0);
+        assert_eq!(course.average_completion_time(), Some(2 * 86_400));
+        
+        // Enrollments from before the counters existed don't underflow
+        course.record_abandonment().unwrap();
+        assert_eq!(course.active_enrollments, 0);
+    }
+    
+    #[test]
+    fn test_course_stats_funnel() {
+        let mut stats = CourseStats {
+            course: Pubkey::new_from_array([5u8; 32]),
+            lesson_completions: [0; CourseStats::MAX_LESSONS],
+            first_completion_at: None,
+            last_completion_at: None,
+            bump: 255,
+        };
+        
+        stats.record_lesson(0, 1_000).unwrap();
+        stats.record_lesson(0, 2_000).unwrap();
+        stats.record_lesson(1, 3_000).unwrap();
+        
+        // Lesson 1 loses half the learners
+        assert_eq!(stats.lesson_completions[0], 2);
+        assert_eq!(stats.lesson_completions[1], 1);
+        assert_eq!(stats.first_completion_at, Some(1_000));
+        assert_eq!(stats.last_completion_at, Some(3_000));
+        
+        // Beyond bitmap capacity
+        assert!(stats.record_lesson(CourseStats::MAX_LESSONS as u8, 4_000).is_err());
+        
+        // revoke_xp takes a cleared completion back out
+        stats.unrecord_lesson(0).unwrap();
+        assert_eq!(stats.lesson_completions[0], 1);
+        stats.unrecord_lesson(2).unwrap();
+        assert_eq!(stats.lesson_completions[2], 0);
+        assert!(stats.unrecord_lesson(CourseStats::MAX_LESSONS as u8).is_err());
+        
+        let mut data = Vec::new();
+        stats.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), CourseStats::SIZE);
+    }
+    
+    #[test]
+    fn test_course_index_pages() {
+        let page = |scope: u16| CourseIndex {
+            scope,
+            page: 0,
+            entries: Vec::new(),
+            bump: 255,
+        };
+        let mut global = page(CourseIndex::GLOBAL);
+        let mut track = page(1);
+        let mut course = setup_course();
+        let course_key = Pubkey::new_from_array([6u8; 32]);
+        
+        add_to_course_index(&mut course, course_key, &mut global, &mut track).unwrap();
+        assert!(course.is_indexed);
+        assert_eq!(global.entries[0].course, course_key);
+        assert!(track.entries[0].is_active);
+        
+        // Listed once only
+        assert!(add_to_course_index(&mut course, course_key, &mut global, &mut track).is_err());
+        
+        // Track page must match the course's track
+        let mut other = setup_course();
+        let mut wrong_track = page(2);
+        assert!(add_to_course_index(&mut other, course_key, &mut global, &mut wrong_track).is_err());
+        
+        // Deactivation mirrors into the index
+        global.set_active(&course_key, false).unwrap();
+        assert!(!global.entries[0].is_active);
+        assert!(global.set_active(&Pubkey::new_from_array([8u8; 32]), false).is_err());
+        
+        // Pages cap at PAGE_SIZE and still fit the account
+        while !global.is_full() {
+            global.push(course_key, true).unwrap();
+        }
+        assert!(global.push(course_key, true).is_err());
+        let mut data = Vec::new();
+        global.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), CourseIndex::SIZE);
+    }
+    
+    #[test]
+    fn test_track_registry() {
+        let mut track = Track {
+            track_id: 1,
+            name: "x".repeat(Track::MAX_NAME_LEN),
+            description_uri: "u".repeat(Track::MAX_URI_LEN),
+            max_level: 3,
+            level_names: vec!["l".repeat(Track::MAX_LEVEL_NAME_LEN); Track::MAX_LEVELS],
+            collection: Pubkey::default(),
+            is_active: true,
+            created_at: 0,
+            updated_at: 0,
+            _reserved: [0; 16],
+            bump: 255,
+        };
+        let mut data = Vec::new();
+        track.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), Track::SIZE);
+        
+        let names: Vec<String> = ["Beginner", "Intermediate", "Advanced"]
+            .iter()
+            .map(|name| name.to_string())
+            .collect();
+        assert!(Track::validate("Anchor Framework", "", 3, &names).is_ok());
+        assert!(Track::validate(&"x".repeat(Track::MAX_NAME_LEN + 1), "", 3, &names).is_err());
+        assert!(Track::validate("Anchor", &"u".repeat(Track::MAX_URI_LEN + 1), 3, &names).is_err());
+        assert!(Track::validate("Anchor", "", 0, &[]).is_err());
+        
+        // One bounded, non-empty name per level
+        assert!(Track::validate("Anchor", "", 2, &names).is_err());
+        assert!(Track::validate("Anchor", "", 3, &names[..2]).is_err());
+        let long = vec!["l".repeat(Track::MAX_LEVEL_NAME_LEN + 1); 1];
+        assert!(Track::validate("Anchor", "", 1, &long).is_err());
+        assert!(Track::validate("Anchor", "", 1, &[String::new()]).is_err());
+        let too_many = vec!["l".to_string(); Track::MAX_LEVELS + 1];
+        assert!(Track::validate("Anchor", "", Track::MAX_LEVELS as u8 + 1, &too_many).is_err());
+        
+        // Credential level names come from the track
+        track.level_names = names;
+        assert_eq!(track.level_name(1), "Beginner");
+        assert_eq!(track.level_name(3), "Advanced");
+        assert_eq!(track.level_name(0), "Level 0");
+        assert_eq!(track.level_name(4), "Level 4");
+        
+        // Course levels bounded by the track
+        assert!(track.require_accepts(1).is_ok());
+        assert!(track.require_accepts(3).is_ok());
+        assert!(track.require_accepts(0).is_err());
+        assert!(track.require_accepts(4).is_err());
+        
+        track.is_active = false;
+        assert!(track.require_accepts(1).is_err());
+    }
+    
+    #[test]
+    fn test_track_progress() {
+        let mut progress = TrackProgress {
+            learner: Pubkey::new_from_array([1u8; 32]),
+            track_id: 1,
+            credential_asset: None,
+            highest_level: 0,
+            revoked_at: None,
+            completed_courses: Vec::new(),
+            updated_at: 0,
+            bump: 255,
+        };
+        let advanced = Pubkey::new_from_array([3u8; 32]);
+        let beginner = Pubkey::new_from_array([4u8; 32]);
+        
+        progress.record_course(advanced, 3, 100).unwrap();
+        progress.record_course(beginner, 1, 200).unwrap();
+        assert_eq!(progress.courses_completed(), 2);
+        assert_eq!(progress.highest_level, 3);
+        assert_eq!(progress.updated_at, 200);
+        
+        // Each course counts once
+        assert!(progress.record_course(beginner, 1, 300).is_err());
+        assert_eq!(progress.courses_completed(), 2);
+        
+        // Full list still fits the account
+        for i in 2..TrackProgress::MAX_COURSES {
+            progress
+                .record_course(Pubkey::new_from_array([i as u8 + 10; 32]), 1, 400)
+                .unwrap();
+        }
+        assert!(progress.record_course(Pubkey::new_unique(), 1, 500).is_err());
+        progress.credential_asset = Some(Pubkey::new_unique());
+        progress.revoked_at = Some(600);
+        let mut data = Vec::new();
+        progress.try_serialize(&mut data).unwrap();
+        assert_eq!(data.len(), TrackProgress::SIZE);
+    }
+    
+    #[test]
+    fn test_track_progress_adopts_legacy_credential() {
+        let learner = Pubkey::new_from_array([1u8; 32]);
+        let legacy_asset = Pubkey::new_from_array([6u8; 32]);
+        // As created by init_if_needed
+        let fresh = || TrackProgress {
+            learner: Pubkey::default(),
+            track_id: 0,
+            credential_asset: None,
+            highest_level: 0,
+            revoked_at: None,
+            completed_courses: Vec::new(),
+            updated_at: 0,
+            bump: 0,
+        };
+        
+        // Enrollment minted a credential before TrackProgress existed:
+        // issue_credential upgrades that asset instead of minting another
+        let mut progress = fresh();
+        progress.init(learner, 1, Some(legacy_asset), 254);
+        assert_eq!(progress.learner, learner);
+        assert_eq!(progress.track_id, 1);
+        assert_eq!(progress.credential_asset, Some(legacy_asset));
+        assert_eq!(progress.bump, 254);
+        
+        // No earlier credential: the first issue mints
+        let mut progress = fresh();
+        progress.init(learner, 1, None, 254);
+        assert!(progress.credential_asset.is_none());
+    }
+    
+    #[test]
+    fn test_credential_attribute_merge() {
+        let attribute = |key: &str, value: &str| Attribute {
+            key: key.to_string(),
+            value: value.to_string(),
+        };
+        let mut attributes = vec![
+            attribute("track_id", "1"),
+            attribute("track_name", "Anchor Framework"),
+            attribute("level", "3"),
+            attribute("courses_completed", "1"),
+        ];
+        record_credential_completion(&mut attributes, "anchor-advanced", 100);
+        
+        // Finishing a beginner course later keeps level 3
+        let level = credential_level(&attributes).max(1);
+        assert_eq!(level, 3);
+        upsert_attribute(&mut attributes, "level", level.to_string());
+        upsert_attribute(&mut attributes, "courses_completed", "2".to_string());
+        record_credential_completion(&mut attributes, "anchor-beginner", 200);
+        
+        assert_eq!(attributes.len(), 6);
+        assert_eq!(attributes[0].value, "1");
+        assert_eq!(attributes[1].value, "Anchor Framework");
+        assert_eq!(attributes[2].value, "3");
+        assert_eq!(attributes[3].value, "2");
+        assert_eq!(attributes[4].key, "completed:anchor-advanced");
+        assert_eq!(attributes[5].key, "completed:anchor-beginner");
+        assert_eq!(attributes[5].value, "200");
+        
+        // Missing or malformed level reads as 0
+        assert_eq!(credential_level(&[]), 0);
+        assert_eq!(credential_level(&[attribute("level", "x")]), 0);
+    }
+    
+    #[test]
+    fn test_credential_revocation() {
+        let mut attributes = vec![Attribute {
+            key: "level".to_string(),
+            value: "2".to_string(),
+        }];
+        record_credential_completion(&mut attributes, "anchor-beginner", 100);
+        let original = attributes.clone();
+        
+        mark_credential_revoked(&mut attributes, "plagiarised submissions", 500);
+        assert_eq!(attributes.len(), 5);
+        assert_eq!(attributes[2].key, "revoked");
+        assert_eq!(attributes[2].value, "true");
+        assert_eq!(attributes[3].value, "plagiarised submissions");
+        assert_eq!(attributes[4].value, "500");
+        
+        // Reinstating restores the pre-revocation attributes
+        clear_credential_revocation(&mut attributes);
+        assert_eq!(attributes, original);
+    }
+    
     #[test]
     fn test_course_id_constraints() {
         // Empty string
@@ -257,4 +851,534 @@ mod tests {
         assert!(LearnerProfile::SIZE < 150);
         assert!(LearnerProfile::SIZE > 80);
     }
+    // ═══════════════════════════════════════════════════════════════
+    // TESTS: Season Archive
+    // ═══════════════════════════════════════════════════════════════
+    
+    #[test]
+    fn test_season_seeds() {
+        assert_eq!(Season::SEED, b"season");
+    }
+    
+    #[test]
+    fn test_season_size() {
+        assert!(Season::SIZE < 200);
+        assert!(Season::SIZE > 100);
+    }
+    
+    #[test]
+    fn test_season_totals() {
+        let mut season = setup_season();
+        
+        season.record_xp(30).unwrap();
+        season.record_lesson().unwrap();
+        season.record_xp(200).unwrap();
+        season.record_course_finalized().unwrap();
+        
+        assert_eq!(season.total_xp_minted, 230);
+        assert_eq!(season.lessons_completed, 1);
+        assert_eq!(season.courses_finalized, 1);
+        
+        // Overflow is rejected, not wrapped
+        season.total_xp_minted = u64::MAX;
+        assert!(season.record_xp(1).is_err());
+    }
+    #[test]
+    fn test_commit_leaderboard_once() {
+        let mut season = setup_season();
+        let root = [7u8; 32];
+        
+        // Must be closed first
+        assert!(season.commit_leaderboard(root, season.mint).is_err());
+        
+        season.is_closed = true;
+        assert!(season.commit_leaderboard(root, season.mint).is_ok());
+        assert_eq!(season.leaderboard_root, Some(root));
+        
+        // Cannot be overwritten
+        assert!(season.commit_leaderboard([8u8; 32], season.mint).is_err());
+    }
+    
+    #[test]
+    fn test_leaderboard_merkle_proof() {
+        let alice = Pubkey::new_from_array([10u8; 32]);
+        let bob = Pubkey::new_from_array([11u8; 32]);
+        let carol = Pubkey::new_from_array([12u8; 32]);
+        
+        let leaf_a = season_reward_leaf(&alice, 1, 5000, 300);
+        let leaf_b = season_reward_leaf(&bob, 2, 4000, 200);
+        let leaf_c = season_reward_leaf(&carol, 3, 3000, 100);
+        
+        // Build tree with the same sorted-pair hashing
+        let node = |x: [u8; 32], y: [u8; 32]| {
+            let (l, r) = if x <= y { (x, y) } else { (y, x) };
+            anchor_lang::solana_program::hash::hashv(&[&[1u8], &l, &r]).to_bytes()
+        };
+        let ab = node(leaf_a, leaf_b);
+        let root = node(ab, leaf_c);
+        
+        assert!(verify_merkle_proof(&[leaf_b, leaf_c], &root, leaf_a));
+        assert!(verify_merkle_proof(&[leaf_a, leaf_c], &root, leaf_b));
+        assert!(verify_merkle_proof(&[ab], &root, leaf_c));
+        
+        // Tampered amount fails
+        let forged = season_reward_leaf(&alice, 1, 5000, 3000);
+        assert!(!verify_merkle_proof(&[leaf_b, leaf_c], &root, forged));
+    }
+    // ═══════════════════════════════════════════════════════════════
+    // TESTS: XP Multiplier Windows
+    // ═══════════════════════════════════════════════════════════════
+    
+    #[test]
+    fn test_multiplier_window() {
+        let course_key = Pubkey::new_from_array([5u8; 32]);
+        let other_key = Pubkey::new_from_array([6u8; 32]);
+        let mut window = MultiplierWindow {
+            config: Pubkey::new_from_array([0u8; 32]),
+            window_id: 1,
+            starts_at: 1000,
+            ends_at: 2000,
+            multiplier_bps: 20_000,
+            track_id: None,
+            course: None,
+            created_at: 0,
+            bump: 255,
+        };
+        
+        // Time bounds: [starts_at, ends_at)
+        assert!(!window.applies_to(&course_key, 1, 999));
+        assert!(window.applies_to(&course_key, 1, 1000));
+        assert!(!window.applies_to(&course_key, 1, 2000));
+        
+        // Track filter
+        window.track_id = Some(2);
+        assert!(!window.applies_to(&course_key, 1, 1500));
+        assert!(window.applies_to(&course_key, 2, 1500));
+        
+        // Course filter
+        window.course = Some(course_key);
+        assert!(window.applies_to(&course_key, 2, 1500));
+        assert!(!window.applies_to(&other_key, 2, 1500));
+        
+        assert_eq!(window.apply(30).unwrap(), 60);
+        window.multiplier_bps = 15_000;
+        assert_eq!(window.apply(25).unwrap(), 37);
+    }
+    
+    // ═══════════════════════════════════════════════════════════════
+    // TESTS: XP Mint Metadata
+    // ═══════════════════════════════════════════════════════════════
+    
+    #[test]
+    fn test_xp_mint_metadata_sizing() {
+        assert_eq!(xp_mint_name(3), "Superteam Academy XP \u{2014} Season 3");
+        
+        let base = ExtensionType::try_calculate_account_len::<MintState>(&XP_MINT_EXTENSIONS).unwrap();
+        let short = xp_mint_len(
+            &XP_MINT_EXTENSIONS,
+            ExtensionType::TokenGroupMember,
+            xp_mint_name(1),
+            String::new(),
+        )
+        .unwrap();
+        let long = xp_mint_len(
+            &XP_MINT_EXTENSIONS,
+            ExtensionType::TokenGroupMember,
+            xp_mint_name(1),
+            "https://arweave.net/xp".to_string(),
+        )
+        .unwrap();
+        
+        // Rent must cover member + metadata written after allocation
+        assert!(short > base);
+        assert_eq!(long - short, "https://arweave.net/xp".len());
+    }
+    
+    // ═══════════════════════════════════════════════════════════════
+    // TESTS: Signer Registry
+    // ═══════════════════════════════════════════════════════════════
+    
+    fn signer_entry(seed: u8, permissions: u8, daily_xp_quota: u32) -> SignerEntry {
+        SignerEntry {
+            signer: Pubkey::new_from_array([seed; 32]),
+            permissions,
+            daily_xp_quota,
+            xp_today: 0,
+            quota_day: 0,
+            expires_at: None,
+        }
+    }
+    
+    #[test]
+    fn test_signer_permissions() {
+        let lessons = Pubkey::new_from_array([1u8; 32]);
+        let stranger = Pubkey::new_from_array([7u8; 32]);
+        let registry = SignerRegistry {
+            signers: vec![signer_entry(1, SignerRegistry::PERM_COMPLETE_LESSON, 0)],
+            bump: 255,
+        };
+        
+        assert!(registry.check(&lessons, SignerRegistry::PERM_COMPLETE_LESSON, 0).is_ok());
+        assert!(registry.check(&lessons, SignerRegistry::PERM_CREDENTIALS, 0).is_err());
+        assert!(registry.check(&stranger, SignerRegistry::PERM_COMPLETE_LESSON, 0).is_err());
+    }
+    
+    #[test]
+    fn test_signer_daily_quota() {
+        let signer = Pubkey::new_from_array([1u8; 32]);
+        let mut registry = SignerRegistry {
+            signers: vec![signer_entry(1, SignerRegistry::PERM_ALL, 100)],
+            bump: 255,
+        };
+        let day = 86400 * 20000;
+        
+        assert!(registry.authorize(&signer, SignerRegistry::PERM_ACHIEVEMENTS, 60, day).is_ok());
+        assert!(registry.authorize(&signer, SignerRegistry::PERM_ACHIEVEMENTS, 40, day).is_ok());
+        assert!(registry.authorize(&signer, SignerRegistry::PERM_ACHIEVEMENTS, 1, day).is_err());
+        
+        // Quota resets the next day
+        assert!(registry.authorize(&signer, SignerRegistry::PERM_ACHIEVEMENTS, 100, day + 86400).is_ok());
+    }
+    
+    #[test]
+    fn test_signer_rotation_overlap() {
+        let old = Pubkey::new_from_array([1u8; 32]);
+        let next = Pubkey::new_from_array([2u8; 32]);
+        let mut old_entry = signer_entry(1, SignerRegistry::PERM_ALL, 0);
+        old_entry.expires_at = Some(2000);
+        let mut registry = SignerRegistry {
+            signers: vec![old_entry, signer_entry(2, SignerRegistry::PERM_ALL, 0)],
+            bump: 255,
+        };
+        
+        // Both keys accepted before the deadline
+        assert!(registry.check(&old, SignerRegistry::PERM_FINALIZE, 1999).is_ok());
+        assert!(registry.check(&next, SignerRegistry::PERM_FINALIZE, 1999).is_ok());
+        
+        // Old key dropped at the deadline, even before pruning
+        assert!(registry.check(&old, SignerRegistry::PERM_FINALIZE, 2000).is_err());
+        registry.prune_expired(2000);
+        assert_eq!(registry.signers.len(), 1);
+        assert!(registry.find(&old).is_none());
+    }
+    
+    #[test]
+    fn test_signer_rotation_keeps_daily_usage() {
+        let old = Pubkey::new_from_array([1u8; 32]);
+        let next = Pubkey::new_from_array([2u8; 32]);
+        let mut registry = SignerRegistry {
+            signers: vec![signer_entry(1, SignerRegistry::PERM_ALL, 100)],
+            bump: 255,
+        };
+        let day = 86400 * 20000;
+        assert!(registry.authorize(&old, SignerRegistry::PERM_COMPLETE_LESSON, 80, day).is_ok());
+        
+        // The new key continues today's count instead of starting at zero
+        let successor = registry.signers[0].successor(next);
+        assert_eq!(successor.permissions, SignerRegistry::PERM_ALL);
+        assert_eq!(successor.daily_xp_quota, 100);
+        assert!(successor.expires_at.is_none());
+        registry.signers.push(successor);
+        assert!(registry.authorize(&next, SignerRegistry::PERM_COMPLETE_LESSON, 21, day).is_err());
+        assert!(registry.authorize(&next, SignerRegistry::PERM_COMPLETE_LESSON, 20, day).is_ok());
+    }
+    
+    // ═══════════════════════════════════════════════════════════════
+    // TESTS: Admin Council
+    // ═══════════════════════════════════════════════════════════════
+    
+    #[test]
+    fn test_admin_council_validity() {
+        let a = Pubkey::new_from_array([1u8; 32]);
+        let b = Pubkey::new_from_array([2u8; 32]);
+        let c = Pubkey::new_from_array([3u8; 32]);
+        
+        assert!(AdminCouncil::is_valid(&[a, b, c], 2));
+        assert!(!AdminCouncil::is_valid(&[a, b, c], 0));
+        assert!(!AdminCouncil::is_valid(&[a, b, c], 4));
+        // Duplicate members would let one key approve twice
+        assert!(!AdminCouncil::is_valid(&[a, a, b], 2));
+        
+        let too_many: Vec<Pubkey> = (0..17u8).map(|i| Pubkey::new_from_array([i; 32])).collect();
+        assert!(!AdminCouncil::is_valid(&too_many, 2));
+    }
+    
+    #[test]
+    fn test_admin_council_update() {
+        let a = Pubkey::new_from_array([1u8; 32]);
+        let b = Pubkey::new_from_array([2u8; 32]);
+        let c = Pubkey::new_from_array([3u8; 32]);
+        let mut council = AdminCouncil {
+            config: Pubkey::new_from_array([9u8; 32]),
+            members: vec![a, b],
+            threshold: 2,
+            next_proposal_id: 4,
+            members_epoch: 0,
+            bump: 255,
+        };
+        
+        // Rejected updates leave the council untouched
+        assert!(council.update(vec![a, a], 1).is_err());
+        assert!(council.update(vec![a, b, c], 4).is_err());
+        assert_eq!(council.members, vec![a, b]);
+        assert_eq!(council.members_epoch, 0);
+        
+        // Member indices shift, so older approvals must lapse
+        council.update(vec![c, a], 1).unwrap();
+        assert_eq!(council.members, vec![c, a]);
+        assert_eq!(council.threshold, 1);
+        assert_eq!(council.members_epoch, 1);
+        assert_eq!(council.next_proposal_id, 4);
+        
+        // Full council fits the account
+        council.members = (0..16u8).map(|i| Pubkey::new_from_array([i; 32])).collect();
+        assert_eq!(council.try_to_vec().unwrap().len() + 8, AdminCouncil::SIZE);
+        
+        let action = AdminAction::UpdateCouncil { members: vec![a, b, c], threshold: 2 };
+        assert_eq!(&action.data()[..8], crate::instruction::UpdateAdminCouncil::DISCRIMINATOR);
+    }
+    
+    #[test]
+    fn test_council_admin_keys() {
+        let authority = Pubkey::new_from_array([1u8; 32]);
+        let council = Pubkey::new_from_array([9u8; 32]);
+        let stranger = Pubkey::new_from_array([4u8; 32]);
+        let mut config = setup_config();
+        config.authority = authority;
+        
+        assert!(config.is_admin(&authority));
+        assert!(!config.is_admin(&council));
+        
+        // init_admin_council adds the council next to the authority,
+        // which keeps every `has_one = authority` instruction
+        config.council = council;
+        assert!(config.is_admin(&council));
+        assert!(config.is_admin(&authority));
+        assert!(!config.is_admin(&stranger));
+        assert_eq!(config.authority, authority);
+    }
+    
+    #[test]
+    fn test_admin_proposal_approvals() {
+        let action = AdminAction::CloseSeason { leaderboard_root: Some([7u8; 32]) };
+        let mut proposal = AdminProposal {
+            proposal_id: 0,
+            proposer: Pubkey::new_from_array([1u8; 32]),
+            action: action.clone(),
+            approvals: 0,
+            created_at: 0,
+            expires_at: 1000,
+            members_epoch: 0,
+            bump: 255,
+        };
+        
+        assert!(proposal.approve(0));
+        assert!(!proposal.approve(0));
+        assert!(proposal.approve(15));
+        assert_eq!(proposal.approval_count(), 2);
+        
+        // Account space covers the serialized proposal
+        assert_eq!(
+            proposal.try_to_vec().unwrap().len() + 8,
+            AdminProposal::space(&action)
+        );
+        
+        // Action data targets the matching instruction
+        assert_eq!(&action.data()[..8], crate::instruction::CloseSeason::DISCRIMINATOR);
+    }
+    
+    // ═══════════════════════════════════════════════════════════════
+    // TESTS: Schema Migrations
+    // ═══════════════════════════════════════════════════════════════
+    
+    /// Config as laid out before schema versioning
+    #[derive(AnchorSerialize)]
+    struct ConfigV0 {
+        authority: Pubkey,
+        backend_signer: Pubkey,
+        current_season: u16,
+        current_mint: Pubkey,
+        season_closed: bool,
+        season_started_at: i64,
+        max_daily_xp: u32,
+        max_achievement_xp: u32,
+        _reserved: [u8; 32],
+        bump: u8,
+    }
+    
+    /// LearnerProfile as laid out before schema versioning
+    #[derive(AnchorSerialize)]
+    struct LearnerProfileV0 {
+        authority: Pubkey,
+        current_streak: u16,
+        longest_streak: u16,
+        last_activity_date: i64,
+        streak_freezes: u8,
+        achievement_flags: [u64; 4],
+        xp_earned_today: u32,
+        last_xp_day: u16,
+        referral_count: u16,
+        has_referrer: bool,
+        _reserved: [u8; 16],
+        bump: u8,
+    }
+    
+    /// Course as laid out before schema versioning
+    #[derive(AnchorSerialize)]
+    struct CourseV0 {
+        course_id: String,
+        creator: Pubkey,
+        authority: Pubkey,
+        content_tx_id: [u8; 32],
+        version: u16,
+        lesson_count: u8,
+        difficulty: u8,
+        xp_per_lesson: u32,
+        track_id: u16,
+        track_level: u8,
+        prerequisite: Option<Pubkey>,
+        completion_bonus_xp: u32,
+        creator_reward_xp: u32,
+        min_completions_for_reward: u16,
+        total_completions: u32,
+        total_enrollments: u32,
+        is_active: bool,
+        created_at: i64,
+        updated_at: i64,
+        _reserved: [u8; 16],
+        bump: u8,
+    }
+    
+    /// Old account bytes grown to `len` the way migrate_account does
+    fn grow_v0<T: Discriminator>(old: &impl AnchorSerialize, len: usize) -> Vec<u8> {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        old.serialize(&mut data).unwrap();
+        assert!(data.len() <= len);
+        data.resize(len, 0);
+        data
+    }
+    
+    #[test]
+    fn test_schema_migration() {
+        let authority = Pubkey::new_from_array([7u8; 32]);
+        let mint = Pubkey::new_from_array([2u8; 32]);
+        
+        // Config: every old field keeps its offset, new ones decode as defaults
+        let data = grow_v0::<Config>(
+            &ConfigV0 {
+                authority,
+                backend_signer: Pubkey::new_from_array([1u8; 32]),
+                current_season: 3,
+                current_mint: mint,
+                season_closed: false,
+                season_started_at: 1_000_000,
+                max_daily_xp: 2000,
+                max_achievement_xp: 500,
+                _reserved: [0; 32],
+                bump: 254,
+            },
+            Config::SIZE,
+        );
+        let mut config = Config::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(config.authority, authority);
+        assert_eq!(config.current_season, 3);
+        assert_eq!(config.current_mint, mint);
+        assert_eq!(config.season_started_at, 1_000_000);
+        assert_eq!(config.max_daily_xp, 2000);
+        assert_eq!(config.max_achievement_xp, 500);
+        assert_eq!(config.bump, 254);
+        assert_eq!(config.schema_version, 0);
+        assert_eq!(config.academy_id, 0);
+        assert_eq!(config.paused, 0);
+        assert_eq!(config.season_ends_at, None);
+        assert_eq!(config.pending_authority, None);
+        assert_eq!(config.pauser, Pubkey::default());
+        assert_eq!(config.council, Pubkey::default());
+        
+        assert!(config.migrate().is_ok());
+        assert_eq!(config.schema_version, Config::SCHEMA_VERSION);
+        assert_eq!(config.level_base_xp, 0);
+        assert_eq!(config.level_growth_bps, 10_000);
+        
+        // Current layout cannot be migrated again
+        assert!(config.migrate().is_err());
+        
+        // LearnerProfile
+        let data = grow_v0::<LearnerProfile>(
+            &LearnerProfileV0 {
+                authority,
+                current_streak: 4,
+                longest_streak: 9,
+                last_activity_date: 1_000_000,
+                streak_freezes: 2,
+                achievement_flags: [1, 0, 0, 1 << 63],
+                xp_earned_today: 150,
+                last_xp_day: 11,
+                referral_count: 3,
+                has_referrer: true,
+                _reserved: [0; 16],
+                bump: 253,
+            },
+            LearnerProfile::SIZE,
+        );
+        let mut learner = LearnerProfile::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(learner.authority, authority);
+        assert_eq!(learner.longest_streak, 9);
+        assert_eq!(learner.achievement_flags, [1, 0, 0, 1 << 63]);
+        assert_eq!(learner.referral_count, 3);
+        assert!(learner.has_referrer);
+        assert_eq!(learner.bump, 253);
+        assert_eq!(learner.level, 0);
+        assert_eq!(learner.lifetime_xp, 0);
+        assert_eq!(learner.season_xp, 0);
+        
+        assert!(learner.migrate().is_ok());
+        assert_eq!(learner.schema_version, LearnerProfile::SCHEMA_VERSION);
+        assert!(learner.migrate().is_err());
+        
+        // Course: v0 goes straight to v2
+        let prerequisite = Pubkey::new_from_array([8u8; 32]);
+        let data = grow_v0::<Course>(
+            &CourseV0 {
+                course_id: "anchor-beginner".to_string(),
+                creator: authority,
+                authority,
+                content_tx_id: [9u8; 32],
+                version: 2,
+                lesson_count: 10,
+                difficulty: 1,
+                xp_per_lesson: 50,
+                track_id: 1,
+                track_level: 1,
+                prerequisite: Some(prerequisite),
+                completion_bonus_xp: 200,
+                creator_reward_xp: 20,
+                min_completions_for_reward: 5,
+                total_completions: 4,
+                total_enrollments: 12,
+                is_active: true,
+                created_at: 1_000_000,
+                updated_at: 1_000_500,
+                _reserved: [0; 16],
+                bump: 252,
+            },
+            Course::SIZE,
+        );
+        let mut course = Course::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(course.course_id, "anchor-beginner");
+        assert_eq!(course.prerequisite, Some(prerequisite));
+        assert_eq!(course.total_completions, 4);
+        assert_eq!(course.total_enrollments, 12);
+        assert!(course.is_active);
+        assert_eq!(course.updated_at, 1_000_500);
+        assert_eq!(course.bump, 252);
+        assert!(!course.is_frozen);
+        assert!(!course.is_indexed);
+        assert_eq!(course.active_enrollments, 0);
+        assert_eq!(course.total_completion_time, 0);
+        
+        assert!(course.migrate().is_ok());
+        assert_eq!(course.schema_version, Course::SCHEMA_VERSION);
+        assert!(course.migrate().is_err());
+    }
 }
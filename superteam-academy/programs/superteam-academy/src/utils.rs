@@ -1,13 +1,83 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token_2022::spl_token_2022::extension::{
-    ExtensionType, 
-    metadata_pointer::MetadataPointer,
-    group_pointer::GroupPointer,
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::ExtensionType,
+    state::Mint as MintState,
 };
+use anchor_spl::token_2022_extensions::spl_token_metadata_interface::state::TokenMetadata;
 use anchor_spl::token_interface::{Token2022, Mint, TokenAccount};
 use anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType;
 
 use crate::{state::*, error::AcademyError};
+use anchor_lang::system_program::{transfer, Transfer};
+use mpl_core::fetch_asset_plugin;
+use mpl_core::instructions::UpdatePluginV1CpiBuilder;
+use mpl_core::types::{Attribute, Attributes, Plugin, PluginType};
+
+/// Token-2022 extensions every season XP mint is created with
+pub const XP_MINT_EXTENSIONS: [ExtensionType; 4] = [
+    ExtensionType::NonTransferable,
+    ExtensionType::PermanentDelegate,
+    ExtensionType::MetadataPointer,
+    ExtensionType::GroupMemberPointer,
+];
+
+/// Token-2022 extensions of the XP group mint
+pub const XP_GROUP_EXTENSIONS: [ExtensionType; 2] = [
+    ExtensionType::GroupPointer,
+    ExtensionType::MetadataPointer,
+];
+
+/// Seed of the XP token group mint PDA
+pub const XP_GROUP_SEED: &[u8] = b"xp_group";
+
+/// Symbol shown by wallets for every XP mint
+pub const XP_SYMBOL: &str = "XP";
+
+/// Name of the XP token group
+pub const XP_GROUP_NAME: &str = "Superteam Academy XP";
+
+/// Longest metadata URI accepted for XP mints
+pub const MAX_XP_URI_LEN: usize = 200;
+
+/// Credential attribute holding the track level
+pub const CREDENTIAL_LEVEL_KEY: &str = "level";
+
+/// Prefix of credential completion history attributes (`completed:<course_id>`)
+pub const CREDENTIAL_HISTORY_PREFIX: &str = "completed:";
+
+/// Credential attributes set while a credential is revoked
+pub const CREDENTIAL_REVOKED_KEY: &str = "revoked";
+pub const CREDENTIAL_REVOKED_REASON_KEY: &str = "revoked_reason";
+pub const CREDENTIAL_REVOKED_AT_KEY: &str = "revoked_at";
+
+/// On-mint metadata name of a season XP mint
+pub fn xp_mint_name(season: u16) -> String {
+    format!("{} \u{2014} Season {}", XP_GROUP_NAME, season)
+}
+
+/// Final mint size once the variable-length extensions written after
+/// `InitializeMint2` (group / member and metadata) are in place.
+/// The account is allocated for `extensions` only; Token-2022 reallocs
+/// the rest, so rent must be prefunded for this size.
+pub fn xp_mint_len(
+    extensions: &[ExtensionType],
+    group_extension: ExtensionType,
+    name: String,
+    uri: String,
+) -> Result<usize> {
+    let mut all = extensions.to_vec();
+    all.push(group_extension);
+    let base = ExtensionType::try_calculate_account_len::<MintState>(&all)?;
+    
+    let metadata = TokenMetadata {
+        name,
+        symbol: XP_SYMBOL.to_string(),
+        uri,
+        ..Default::default()
+    };
+    Ok(base + metadata.tlv_size_of()?)
+}
 
 /// Check and update daily XP for rate limiting
 pub fn check_and_update_daily_xp(
@@ -37,6 +107,68 @@ pub fn check_and_update_daily_xp(
     Ok(())
 }
 
+/// Apply an optional multiplier window to a base XP amount.
+/// Returns `(xp, multiplier_bps)`.
+pub fn apply_multiplier(
+    window: Option<&MultiplierWindow>,
+    course_key: &Pubkey,
+    course: &Course,
+    base_xp: u32,
+    now: i64,
+) -> Result<(u32, u16)> {
+    match window {
+        Some(window) => {
+            require!(
+                window.applies_to(course_key, course.track_id, now),
+                AcademyError::MultiplierNotApplicable
+            );
+            Ok((window.apply(base_xp)?, window.multiplier_bps))
+        }
+        None => Ok((base_xp, MultiplierWindow::BASE_BPS)),
+    }
+}
+
+/// List `course` on its global and track CourseIndex pages
+pub fn add_to_course_index(
+    course: &mut Course,
+    course_key: Pubkey,
+    global_index: &mut CourseIndex,
+    track_index: &mut CourseIndex,
+) -> Result<()> {
+    require!(!course.is_indexed, AcademyError::CourseAlreadyIndexed);
+    require!(
+        global_index.scope == CourseIndex::GLOBAL && track_index.scope == course.track_id,
+        AcademyError::InvalidCourseIndexPage
+    );
+    
+    global_index.push(course_key, course.is_active)?;
+    track_index.push(course_key, course.is_active)?;
+    
+    course.is_indexed = true;
+    course.global_index_page = global_index.page;
+    course.track_index_page = track_index.page;
+    Ok(())
+}
+
+/// Credit minted XP to a learner profile and refresh their level.
+/// Returns `(old_level, new_level)` when the learner levelled up.
+pub fn credit_learner_xp(
+    learner: &mut LearnerProfile,
+    config: &Config,
+    amount: u64,
+    season: u16,
+) -> Result<Option<(u16, u16)>> {
+    learner.credit_xp(amount, season, config.current_season)?;
+    
+    let old_level = learner.level;
+    let new_level = config.level_for_xp(learner.lifetime_xp);
+    if new_level > old_level {
+        learner.level = new_level;
+        return Ok(Some((old_level, new_level)));
+    }
+    Ok(None)
+}
+
 /// Update streak based on activity
 pub fn update_streak(
     learner: &mut LearnerProfile,
@@ -74,7 +206,7 @@ pub fn update_streak(
             .current_streak
             .checked_add(1)
             .ok_or(AcademyError::Overflow)?;
-        StreakUpdate::SavedByFreezes { 
+        StreakUpdate::SavedByFreezes {
             freezes_used,
             new_streak: learner.current_streak,
         }
@@ -115,3 +247,157 @@ pub fn check_milestone(streak: u16) -> Option<u16> {
 pub fn current_day() -> Result<u64> {
     Ok((Clock::get()?.unix_timestamp / 86400) as u64)
 }
+
+/// Leaf of a season leaderboard Merkle tree
+pub fn season_reward_leaf(
+    learner: &Pubkey,
+    rank: u16,
+    season_xp: u64,
+    amount: u64,
+) -> [u8; 32] {
+    hashv(&[
+        &[0u8], // leaf domain
+        learner.as_ref(),
+        &rank.to_le_bytes(),
+        &season_xp.to_le_bytes(),
+        &amount.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Verify a Merkle proof (sorted pairs, no direction bits)
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let mut node = leaf;
+    for sibling in proof {
+        let (left, right) = if node <= *sibling {
+            (node, *sibling)
+        } else {
+            (*sibling, node)
+        };
+        node = hashv(&[&[1u8], &left, &right]).to_bytes(); // node domain
+    }
+    node == *root
+}
+
+/// Grow `account` to `new_len` (payer tops up rent), then deserialize it,
+/// run `upgrade` and write it back.
+///
+/// Layout changes never move existing fields: fixed-size fields are carved
+/// from `_reserved` (always zero) and anything else is appended after
+/// `bump`, so both decode as defaults (0 / false / None). Owner and
+/// discriminator are checked on deserialize; a failure reverts the realloc
+/// with the transaction.
+pub fn migrate_account<'info, T>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+    upgrade: impl FnOnce(&mut T) -> Result<()>,
+) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Owner,
+{
+    if account.data_len() < new_len {
+        let required = Rent::get()?.minimum_balance(new_len);
+        let top_up = required.saturating_sub(account.lamports());
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: account.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        account.resize(new_len)?;
+    }
+    
+    require_keys_eq!(*account.owner, T::owner(), ErrorCode::AccountOwnedByWrongProgram);
+    let mut migrated = T::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+    upgrade(&mut migrated)?;
+    migrated.try_serialize(&mut &mut account.try_borrow_mut_data()?[..])
+}
+
+/// Set `key` to `value` in a credential attribute list, keeping other keys
+pub fn upsert_attribute(attributes: &mut Vec<Attribute>, key: &str, value: String) {
+    match attributes.iter_mut().find(|attribute| attribute.key == key) {
+        Some(attribute) => attribute.value = value,
+        None => attributes.push(Attribute {
+            key: key.to_string(),
+            value,
+        }),
+    }
+}
+
+/// Level recorded on a credential (0 if missing or malformed)
+pub fn credential_level(attributes: &[Attribute]) -> u8 {
+    attributes
+        .iter()
+        .find(|attribute| attribute.key == CREDENTIAL_LEVEL_KEY)
+        .and_then(|attribute| attribute.value.parse().ok())
+        .unwrap_or(0)
+}
+
+/// Append a course completion to the credential history
+pub fn record_credential_completion(
+    attributes: &mut Vec<Attribute>,
+    course_id: &str,
+    completed_at: i64,
+) {
+    let key = format!("{}{}", CREDENTIAL_HISTORY_PREFIX, course_id);
+    upsert_attribute(attributes, &key, completed_at.to_string());
+}
+
+/// Flag a credential as revoked with `reason` at `now`
+pub fn mark_credential_revoked(attributes: &mut Vec<Attribute>, reason: &str, now: i64) {
+    upsert_attribute(attributes, CREDENTIAL_REVOKED_KEY, "true".to_string());
+    upsert_attribute(attributes, CREDENTIAL_REVOKED_REASON_KEY, reason.to_string());
+    upsert_attribute(attributes, CREDENTIAL_REVOKED_AT_KEY, now.to_string());
+}
+
+/// Drop the revocation attributes, leaving the rest of the credential intact
+pub fn clear_credential_revocation(attributes: &mut Vec<Attribute>) {
+    attributes.retain(|attribute| {
+        attribute.key != CREDENTIAL_REVOKED_KEY
+            && attribute.key != CREDENTIAL_REVOKED_REASON_KEY
+            && attribute.key != CREDENTIAL_REVOKED_AT_KEY
+    });
+}
+
+/// Rewrite a credential's attributes with the revocation flags set
+/// (`revocation = Some((reason, now))`) or cleared (`None`)
+pub fn write_credential_revocation<'info>(
+    config: &Account<'info, Config>,
+    credential_asset: &AccountInfo<'info>,
+    track_collection: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    mpl_core_program: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    revocation: Option<(&str, i64)>,
+) -> Result<()> {
+    let (_, existing, _) = fetch_asset_plugin::<Attributes>(credential_asset, PluginType::Attributes)
+        .map_err(|_| AcademyError::InvalidCredentialAsset)?;
+    let mut attribute_list = existing.attribute_list;
+    match revocation {
+        Some((reason, now)) => mark_credential_revoked(&mut attribute_list, reason, now),
+        None => clear_credential_revocation(&mut attribute_list),
+    }
+    
+    let academy_seed = Config::academy_seed(config.academy_id);
+    let config_seeds = &[Config::SEED, &academy_seed, &[config.bump]];
+    let config_info = config.to_account_info();
+    
+    UpdatePluginV1CpiBuilder::new(mpl_core_program)
+        .asset(credential_asset)
+        .collection(Some(track_collection))
+        .payer(payer)
+        .authority(Some(&config_info))
+        .system_program(system_program)
+        .plugin(Plugin::Attributes(Attributes { attribute_list }))
+        .invoke_signed(&[config_seeds])?;
+    
+    Ok(())
+}
@@ -21,10 +21,18 @@ let learnerProfilePDA: PublicKey;
 let learnerProfileBump: number;
 let coursePDA: PublicKey;
 let courseBump: number;
+let courseStatsPDA: PublicKey;
+let trackPDA: PublicKey;
+let globalIndexPDA: PublicKey;
+let trackIndexPDA: PublicKey;
 let enrollmentPDA: PublicKey;
 let enrollmentBump: number;
 
 const COURSE_ID = "anchor-beginner";
+const ACADEMY_ID = 0;
+const TRACK_ID = 1;
+const GLOBAL_INDEX = 0xffff;
+const MPL_CORE_PROGRAM_ID = new PublicKey('CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d');
 
 describe('Superteam Academy', () => {
   before(async () => {
@@ -46,25 +54,53 @@ describe('Superteam Academy', () => {
     // Wait for airdrop
     await new Promise(resolve => setTimeout(resolve, 2000));
     
-    // Derive PDAs
+    // Derive PDAs (academy 0 keeps the unscoped legacy seeds)
+    const academySeed = ACADEMY_ID === 0
+      ? Buffer.alloc(0)
+      : new anchor.BN(ACADEMY_ID).toArrayLike(Buffer, 'le', 2);
     [configPDA, configBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from('config')],
+      [Buffer.from('config'), academySeed],
       program.programId
     );
+    const scopeSeed = ACADEMY_ID === 0 ? Buffer.alloc(0) : configPDA.toBuffer();
     
     [learnerProfilePDA, learnerProfileBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from('learner'), learner.publicKey.toBuffer()],
+      [Buffer.from('learner'), scopeSeed, learner.publicKey.toBuffer()],
       program.programId
     );
     
     [coursePDA, courseBump] = PublicKey.findProgramAddressSync(
-      [Buffer.from('course'), Buffer.from(COURSE_ID)],
+      [Buffer.from('course'), scopeSeed, Buffer.from(COURSE_ID)],
       program.programId
     );
     
+    [courseStatsPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('course_stats'), coursePDA.toBuffer()],
+      program.programId
+    );
+    
+    [trackPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from('track'), configPDA.toBuffer(), new anchor.BN(TRACK_ID).toArrayLike(Buffer, 'le', 2)],
+      program.programId
+    );
+    
+    const indexPage = (scope: number, page: number) =>
+      PublicKey.findProgramAddressSync(
+        [
+          Buffer.from('course_index'),
+          configPDA.toBuffer(),
+          new anchor.BN(scope).toArrayLike(Buffer, 'le', 2),
+          new anchor.BN(page).toArrayLike(Buffer, 'le', 2),
+        ],
+        program.programId
+      )[0];
+    globalIndexPDA = indexPage(GLOBAL_INDEX, 0);
+    trackIndexPDA = indexPage(TRACK_ID, 0);
+    
     [enrollmentPDA, enrollmentBump] = PublicKey.findProgramAddressSync(
       [
         Buffer.from('enrollment'),
+        scopeSeed,
         Buffer.from(COURSE_ID),
         learner.publicKey.toBuffer(),
       ],
@@ -76,8 +112,11 @@ describe('Superteam Academy', () => {
     it('Initialize platform', async () => {
       const tx = await program.methods
         .initialize({
+          academyId: ACADEMY_ID,
           maxDailyXp: 2000,
           maxAchievementXp: 500,
+          levelBaseXp: 100,
+          levelGrowthBps: 15000,
         })
         .accounts({
           payer: authority.publicKey,
@@ -97,6 +136,8 @@ describe('Superteam Academy', () => {
       assert.equal(config.backendSigner.toBase58(), backendSigner.publicKey.toBase58());
       assert.equal(config.maxDailyXp, 2000);
       assert.equal(config.maxAchievementXp, 500);
+      assert.equal(config.levelBaseXp, 100);
+      assert.equal(config.levelGrowthBps, 15000);
       assert.equal(config.currentSeason, 0);
       assert.equal(config.seasonClosed, true);
     });
@@ -108,17 +149,21 @@ describe('Superteam Academy', () => {
     });
     
     it('Update config', async () => {
-      const newBackendSigner = Keypair.generate().publicKey;
-      
       const tx = await program.methods
         .updateConfig({
-          backendSigner: newBackendSigner,
           maxDailyXp: 2500,
           maxAchievementXp: null,
+          levelBaseXp: null,
+          levelGrowthBps: null,
+          pauser: null,
+          configTimelock: null,
         })
         .accounts({
           config: configPDA,
           authority: authority.publicKey,
+          payer: authority.publicKey,
+          pendingChange: null,
+          systemProgram: SystemProgram.programId,
         })
         .signers([authority])
         .rpc();
@@ -128,9 +173,132 @@ describe('Superteam Academy', () => {
       const config = await program.account.config.fetch(configPDA);
       assert.equal(config.maxDailyXp, 2500);
     });
+    
+    it('Admin council leaves authority-only instructions usable', async () => {
+      const [adminCouncilPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('admin_council'), configPDA.toBuffer()],
+        program.programId
+      );
+      const member = Keypair.generate();
+      
+      await program.methods
+        .initAdminCouncil([authority.publicKey, member.publicKey], 1)
+        .accounts({
+          payer: authority.publicKey,
+          config: configPDA,
+          authority: authority.publicKey,
+          adminCouncil: adminCouncilPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const config = await program.account.config.fetch(configPDA);
+      assert.equal(config.council.toBase58(), adminCouncilPDA.toBase58());
+      assert.equal(config.authority.toBase58(), authority.publicKey.toBase58());
+      
+      // create_multiplier_window is not a council action (has_one = authority)
+      const windowId = 1;
+      const [windowPDA] = PublicKey.findProgramAddressSync(
+        [Buffer.from('multiplier'), configPDA.toBuffer(), new anchor.BN(windowId).toArrayLike(Buffer, 'le', 4)],
+        program.programId
+      );
+      const now = Math.floor(Date.now() / 1000);
+      
+      await program.methods
+        .createMultiplierWindow(windowId, {
+          startsAt: new anchor.BN(now),
+          endsAt: new anchor.BN(now + 3600),
+          multiplierBps: 20000,
+          trackId: null,
+          course: null,
+        })
+        .accounts({
+          payer: authority.publicKey,
+          config: configPDA,
+          authority: authority.publicKey,
+          multiplierWindow: windowPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const window = await program.account.multiplierWindow.fetch(windowPDA);
+      assert.equal(window.multiplierBps, 20000);
+      assert.equal(window.config.toBase58(), configPDA.toBase58());
+    });
   });
   
   describe('Course Management', () => {
+    it('Create track', async () => {
+      await program.methods
+        .createTrack(TRACK_ID, {
+          name: 'Anchor Framework',
+          descriptionUri: 'https://academy.superteam.fun/tracks/anchor.json',
+          maxLevel: 3,
+          levelNames: ['Beginner', 'Intermediate', 'Advanced'],
+        })
+        .accounts({
+          payer: authority.publicKey,
+          config: configPDA,
+          authority: authority.publicKey,
+          track: trackPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority])
+        .rpc();
+      
+      const track = await program.account.track.fetch(trackPDA);
+      assert.equal(track.name, 'Anchor Framework');
+      assert.equal(track.maxLevel, 3);
+      assert.equal(track.isActive, true);
+    });
+    
+    it('Create track collection', async () => {
+      const collection = Keypair.generate();
+      
+      await program.methods
+        .createTrackCollection('https://academy.superteam.fun/tracks/anchor-collection.json')
+        .accounts({
+          payer: authority.publicKey,
+          config: configPDA,
+          authority: authority.publicKey,
+          track: trackPDA,
+          collection: collection.publicKey,
+          mplCoreProgram: MPL_CORE_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([authority, collection])
+        .rpc();
+      
+      const track = await program.account.track.fetch(trackPDA);
+      assert.equal(track.collection.toBase58(), collection.publicKey.toBase58());
+    });
+    
+    it('Open course index pages', async () => {
+      for (const [scope, courseIndex, track] of [
+        [GLOBAL_INDEX, globalIndexPDA, null],
+        [TRACK_ID, trackIndexPDA, trackPDA],
+      ] as const) {
+        await program.methods
+          .createCourseIndexPage(scope, 0)
+          .accounts({
+            payer: authority.publicKey,
+            config: configPDA,
+            track,
+            previousPage: null,
+            courseIndex,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([authority])
+          .rpc();
+      }
+      
+      const index = await program.account.courseIndex.fetch(globalIndexPDA);
+      assert.equal(index.scope, GLOBAL_INDEX);
+      assert.equal(index.entries.length, 0);
+    });
+    
     it('Create course', async () => {
       const contentTxId = new Array(32).fill(0);
       
@@ -142,7 +310,7 @@ describe('Superteam Academy', () => {
           lessonCount: 10,
           difficulty: 1,
           xpPerLesson: 30,
-          trackId: 1,
+          trackId: TRACK_ID,
           trackLevel: 1,
           prerequisite: null,
           completionBonusXp: 200,
@@ -153,7 +321,11 @@ describe('Superteam Academy', () => {
           payer: authority.publicKey,
           config: configPDA,
           authority: authority.publicKey,
+          track: trackPDA,
           course: coursePDA,
+          courseStats: courseStatsPDA,
+          globalIndex: globalIndexPDA,
+          trackIndex: trackIndexPDA,
           prerequisite: null,
           systemProgram: SystemProgram.programId,
         })
@@ -164,6 +336,14 @@ describe('Superteam Academy', () => {
       
       const course = await program.account.course.fetch(coursePDA);
       assert.equal(course.courseId, COURSE_ID);
+      
+      const stats = await program.account.courseStats.fetch(courseStatsPDA);
+      assert.equal(stats.course.toBase58(), coursePDA.toBase58());
+      assert.equal(stats.firstCompletionAt, null);
+      
+      const index = await program.account.courseIndex.fetch(trackIndexPDA);
+      assert.equal(index.entries[0].course.toBase58(), coursePDA.toBase58());
+      assert.equal(course.isIndexed, true);
       assert.equal(course.lessonCount, 10);
       assert.equal(course.difficulty, 1);
       assert.equal(course.xpPerLesson, 30);
@@ -182,8 +362,11 @@ describe('Superteam Academy', () => {
           minCompletionsForReward: null,
         })
         .accounts({
+          config: configPDA,
           course: coursePDA,
           authority: creator.publicKey,
+          globalIndex: globalIndexPDA,
+          trackIndex: trackIndexPDA,
         })
         .signers([creator])
         .rpc();
@@ -203,6 +386,7 @@ describe('Superteam Academy', () => {
         .accounts({
           payer: learner.publicKey,
           learner: learner.publicKey,
+          config: configPDA,
           profile: learnerProfilePDA,
           systemProgram: SystemProgram.programId,
         })
@@ -223,6 +407,7 @@ describe('Superteam Academy', () => {
         .accounts({
           payer: learner.publicKey,
           learner: learner.publicKey,
+          config: configPDA,
           learnerProfile: learnerProfilePDA,
           course: coursePDA,
           enrollment: enrollmentPDA,